@@ -0,0 +1,197 @@
+//! Builder for constructing [`Touch`] values
+
+use crate::{Phase, PointerButton, Tool, Touch, TouchPoint, UnitAngle};
+
+/// Builder for [`Touch`], useful when constructing stylus touches with optional fields
+///
+/// Defaults to ID 0 at the origin, with [`Phase::Started`] and [`Tool::Finger`].
+///
+/// ```
+/// # use embedded_touch::{builder::TouchBuilder, Phase, TouchPoint};
+/// let touch = TouchBuilder::new()
+///     .id(2)
+///     .location(TouchPoint::new(10, 20))
+///     .phase(Phase::Moved)
+///     .stylus_pressure(150)
+///     .build();
+/// assert_eq!(touch.tool.pressure(), Some(150));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TouchBuilder {
+    touch: Touch,
+}
+
+impl TouchBuilder {
+    /// Create a new builder with the default touch
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            touch: Touch::new(0, TouchPoint::ORIGIN, Phase::Started, Tool::Finger),
+        }
+    }
+
+    /// Set the touch ID
+    #[must_use]
+    pub fn id(mut self, id: u8) -> Self {
+        self.touch.id = id;
+        self
+    }
+
+    /// Set the touch location
+    #[must_use]
+    pub fn location(mut self, location: TouchPoint) -> Self {
+        self.touch.location = location;
+        self
+    }
+
+    /// Set the touch phase
+    #[must_use]
+    pub fn phase(mut self, phase: Phase) -> Self {
+        self.touch.phase = phase;
+        self
+    }
+
+    /// Use a finger as the tool
+    #[must_use]
+    pub fn finger(mut self) -> Self {
+        self.touch.tool = Tool::Finger;
+        self
+    }
+
+    /// Use a virtual pointer with the given button as the tool, without a wheel delta
+    #[must_use]
+    pub fn pointer(mut self, button: PointerButton) -> Self {
+        self.touch.tool = Tool::Pointer {
+            button,
+            wheel_delta: None,
+        };
+        self
+    }
+
+    /// Use a stylus as the tool, with the given pressure in grams
+    ///
+    /// Other stylus fields are preserved if the tool is already a stylus.
+    #[must_use]
+    pub fn stylus_pressure(mut self, value: u16) -> Self {
+        if let Tool::Stylus { pressure, .. } = self.stylus() {
+            *pressure = Some(value);
+        }
+        self
+    }
+
+    /// Use a stylus as the tool, with the given barrel pressure in grams
+    ///
+    /// Other stylus fields are preserved if the tool is already a stylus.
+    #[must_use]
+    pub fn stylus_barrel_pressure(mut self, value: u16) -> Self {
+        if let Tool::Stylus {
+            barrel_pressure, ..
+        } = self.stylus()
+        {
+            *barrel_pressure = Some(value);
+        }
+        self
+    }
+
+    /// Use a stylus as the tool, with the given tilt
+    ///
+    /// Other stylus fields are preserved if the tool is already a stylus.
+    #[must_use]
+    pub fn stylus_tilt(mut self, value: UnitAngle) -> Self {
+        if let Tool::Stylus { tilt, .. } = self.stylus() {
+            *tilt = Some(value);
+        }
+        self
+    }
+
+    /// Use a stylus as the tool, with the given azimuth
+    ///
+    /// Other stylus fields are preserved if the tool is already a stylus.
+    #[must_use]
+    pub fn stylus_azimuth(mut self, value: UnitAngle) -> Self {
+        if let Tool::Stylus { azimuth, .. } = self.stylus() {
+            *azimuth = Some(value);
+        }
+        self
+    }
+
+    /// Build the touch
+    #[must_use]
+    pub fn build(self) -> Touch {
+        self.touch
+    }
+
+    /// Switches the tool to a stylus if it isn't one already
+    fn stylus(&mut self) -> &mut Tool {
+        if !self.touch.tool.is_stylus() {
+            self.touch.tool = Tool::Stylus {
+                pressure: None,
+                barrel_pressure: None,
+                tilt: None,
+                azimuth: None,
+            };
+        }
+        &mut self.touch.tool
+    }
+}
+
+impl Default for TouchBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_default_touch() {
+        let touch = TouchBuilder::new().build();
+        assert_eq!(
+            touch,
+            Touch::new(0, TouchPoint::new(0, 0), Phase::Started, Tool::Finger)
+        );
+    }
+
+    #[test]
+    fn build_stylus_touch() {
+        let touch = TouchBuilder::new()
+            .id(4)
+            .location(TouchPoint::new(100, 200))
+            .phase(Phase::Moved)
+            .stylus_pressure(300)
+            .stylus_barrel_pressure(40)
+            .stylus_tilt(UnitAngle::from_degrees(45))
+            .stylus_azimuth(UnitAngle::from_degrees(90))
+            .build();
+
+        let expected = Touch {
+            id: 4,
+            location: TouchPoint::new(100, 200),
+            phase: Phase::Moved,
+            tool: Tool::Stylus {
+                pressure: Some(300),
+                barrel_pressure: Some(40),
+                tilt: Some(UnitAngle::from_degrees(45)),
+                azimuth: Some(UnitAngle::from_degrees(90)),
+            },
+        };
+        assert_eq!(touch, expected);
+    }
+
+    #[test]
+    fn later_tool_overrides_stylus() {
+        let touch = TouchBuilder::new()
+            .stylus_pressure(300)
+            .pointer(PointerButton::Secondary)
+            .build();
+        assert_eq!(
+            touch.tool,
+            Tool::Pointer {
+                button: PointerButton::Secondary,
+                wheel_delta: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Calibration for mapping raw panel readings (e.g. resistive ADC values) to
+//! screen pixels
+
+use fixed::types::I32F32;
+
+use crate::{TouchPoint, transform::CoordinateTransform};
+
+/// An affine mapping from raw touch panel readings to screen pixels
+///
+/// The mapping is stored as a 2x3 fixed-point matrix `[[a, b, c], [d, e, f]]` such that
+///
+/// ```text
+/// screen_x = a * raw_x + b * raw_y + c
+/// screen_y = d * raw_x + e * raw_y + f
+/// ```
+///
+/// This corrects for scale, offset, rotation, and skew between the panel and the display
+/// without requiring an FPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffineCalibration {
+    matrix: [[I32F32; 3]; 2],
+}
+
+impl AffineCalibration {
+    /// The identity calibration, mapping raw readings directly to pixels
+    pub const IDENTITY: Self = AffineCalibration {
+        matrix: [
+            [I32F32::ONE, I32F32::ZERO, I32F32::ZERO],
+            [I32F32::ZERO, I32F32::ONE, I32F32::ZERO],
+        ],
+    };
+
+    /// Create a calibration from a previously computed matrix, e.g. one loaded from flash
+    #[must_use]
+    pub const fn from_matrix(matrix: [[I32F32; 3]; 2]) -> Self {
+        Self { matrix }
+    }
+
+    /// Solve for the calibration mapping three raw readings to their known screen
+    /// locations
+    ///
+    /// Returns `None` if the raw points are collinear, or if the resulting coefficients
+    /// are out of range.
+    #[must_use]
+    pub fn from_three_points(raw: [TouchPoint; 3], screen: [TouchPoint; 3]) -> Option<Self> {
+        let x = solve_row(raw, screen.map(|point| point.x))?;
+        let y = solve_row(raw, screen.map(|point| point.y))?;
+        Some(Self { matrix: [x, y] })
+    }
+
+    /// Returns the calibration matrix
+    #[must_use]
+    pub const fn matrix(&self) -> [[I32F32; 3]; 2] {
+        self.matrix
+    }
+
+    /// Map a raw reading to screen pixels
+    ///
+    /// Results are rounded to the nearest pixel, and saturate at the bounds of [`i32`].
+    #[must_use]
+    pub fn apply(&self, raw: TouchPoint) -> TouchPoint {
+        TouchPoint {
+            x: apply_row(self.matrix[0], raw),
+            y: apply_row(self.matrix[1], raw),
+        }
+    }
+}
+
+impl CoordinateTransform for AffineCalibration {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        self.apply(point)
+    }
+}
+
+/// Formats the matrix coefficients as [`f32`]
+#[cfg(feature = "defmt")]
+impl defmt::Format for AffineCalibration {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let matrix = self.matrix.map(|row| row.map(I32F32::to_num::<f32>));
+        defmt::write!(f, "AffineCalibration {{ matrix: {} }}", matrix);
+    }
+}
+
+/// Serialized as the raw bits of each [`I32F32`] coefficient, so round trips are lossless
+#[cfg(feature = "serde")]
+impl serde::Serialize for AffineCalibration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.matrix
+            .map(|row| row.map(I32F32::to_bits))
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AffineCalibration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = <[[i64; 3]; 2]>::deserialize(deserializer)?;
+        Ok(Self::from_matrix(
+            bits.map(|row| row.map(I32F32::from_bits)),
+        ))
+    }
+}
+
+/// A linear mapping from the raw reporting range of a controller to panel pixels
+///
+/// Each raw range is given as `(start, end)`, the raw values reported at pixel 0 and at
+/// the far edge of the panel. An axis is inverted by giving an `end` below `start`, e.g.
+/// `(4095, 0)`. Raw values outside the range are clamped onto the panel edge.
+///
+/// Ranges are tuples rather than [`RangeInclusive`](core::ops::RangeInclusive) because
+/// an inverted axis would be a reversed range such as `4095..=0`, which is empty as an
+/// iterator and rejected by clippy's `reversed_empty_ranges` lint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RangeMap {
+    raw_x: (i32, i32),
+    raw_y: (i32, i32),
+    panel: (u32, u32),
+}
+
+impl RangeMap {
+    /// Create a mapping from raw X and Y ranges to a `(width, height)` pixel panel
+    #[must_use]
+    pub fn from_ranges(raw_x: (i32, i32), raw_y: (i32, i32), panel: (u32, u32)) -> Self {
+        Self {
+            raw_x,
+            raw_y,
+            panel,
+        }
+    }
+
+    /// Map a raw reading to panel pixels
+    ///
+    /// Results are rounded to the nearest pixel, with halves rounded up.
+    #[must_use]
+    pub fn map(&self, raw: TouchPoint) -> TouchPoint {
+        TouchPoint {
+            x: map_axis(raw.x, self.raw_x, self.panel.0),
+            y: map_axis(raw.y, self.raw_y, self.panel.1),
+        }
+    }
+}
+
+impl CoordinateTransform for RangeMap {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        self.map(point)
+    }
+}
+
+fn map_axis(raw: i32, (start, end): (i32, i32), size: u32) -> i32 {
+    let far_edge = i128::from(size.saturating_sub(1));
+    let mut numerator = (i128::from(raw) - i128::from(start)) * far_edge;
+    let mut denominator = i128::from(end) - i128::from(start);
+    if denominator == 0 {
+        return 0;
+    }
+    if denominator < 0 {
+        numerator = -numerator;
+        denominator = -denominator;
+    }
+    let pixel = (2 * numerator + denominator).div_euclid(2 * denominator);
+    // Clamping to the panel keeps the value within 0..=u32::MAX / 2
+    i32::try_from(pixel.clamp(0, far_edge)).unwrap_or(i32::MAX)
+}
+
+/// Solve `a * x + b * y + c = target` for three points using Cramer's rule
+fn solve_row(raw: [TouchPoint; 3], target: [i32; 3]) -> Option<[I32F32; 3]> {
+    let [p1, p2, p3] = raw.map(|point| (i128::from(point.x), i128::from(point.y)));
+    let [t1, t2, t3] = target.map(i128::from);
+
+    let determinant = p1.0 * (p2.1 - p3.1) + p2.0 * (p3.1 - p1.1) + p3.0 * (p1.1 - p2.1);
+    if determinant == 0 {
+        return None;
+    }
+    let a_numerator = t1 * (p2.1 - p3.1) + t2 * (p3.1 - p1.1) + t3 * (p1.1 - p2.1);
+    let b_numerator = p1.0 * (t2 - t3) + p2.0 * (t3 - t1) + p3.0 * (t1 - t2);
+
+    let a = (a_numerator << I32F32::FRAC_NBITS) / determinant;
+    let b = (b_numerator << I32F32::FRAC_NBITS) / determinant;
+    let c = (t1 << I32F32::FRAC_NBITS) - a * p1.0 - b * p1.1;
+
+    Some([
+        I32F32::from_bits(i64::try_from(a).ok()?),
+        I32F32::from_bits(i64::try_from(b).ok()?),
+        I32F32::from_bits(i64::try_from(c).ok()?),
+    ])
+}
+
+fn apply_row(row: [I32F32; 3], raw: TouchPoint) -> i32 {
+    let [a, b, c] = row.map(|coefficient| i128::from(coefficient.to_bits()));
+    let bits = a * i128::from(raw.x) + b * i128::from(raw.y) + c;
+    let half = 1 << (I32F32::FRAC_NBITS - 1);
+    let rounded = (bits + half) >> I32F32::FRAC_NBITS;
+    i32::try_from(rounded).unwrap_or(if rounded < 0 { i32::MIN } else { i32::MAX })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_calibration() {
+        let points = [
+            TouchPoint::new(0, 0),
+            TouchPoint::new(100, 0),
+            TouchPoint::new(0, 100),
+        ];
+        let calibration = AffineCalibration::from_three_points(points, points).unwrap();
+        assert_eq!(calibration, AffineCalibration::IDENTITY);
+        assert_eq!(
+            calibration.apply(TouchPoint::new(-42, 77)),
+            TouchPoint::new(-42, 77)
+        );
+    }
+
+    #[test]
+    fn scaled_and_translated_calibration() {
+        let raw = [
+            TouchPoint::new(0, 0),
+            TouchPoint::new(4000, 0),
+            TouchPoint::new(0, 3000),
+        ];
+        let screen = [
+            TouchPoint::new(10, 20),
+            TouchPoint::new(2010, 20),
+            TouchPoint::new(10, 1520),
+        ];
+        let calibration = AffineCalibration::from_three_points(raw, screen).unwrap();
+        for (raw, screen) in raw.into_iter().zip(screen) {
+            assert_eq!(calibration.apply(raw), screen);
+        }
+        assert_eq!(
+            calibration.apply(TouchPoint::new(2000, 1000)),
+            TouchPoint::new(1010, 520)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_calibration() {
+        let raw = [
+            TouchPoint::new(312, 287),
+            TouchPoint::new(3790, 401),
+            TouchPoint::new(455, 3702),
+        ];
+        let screen = [
+            TouchPoint::new(24, 32),
+            TouchPoint::new(296, 32),
+            TouchPoint::new(24, 208),
+        ];
+        let calibration = AffineCalibration::from_three_points(raw, screen).unwrap();
+
+        let json = serde_json::to_string(&calibration).unwrap();
+        let decoded: AffineCalibration = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, calibration);
+    }
+
+    #[test]
+    fn collinear_points_are_rejected() {
+        let raw = [
+            TouchPoint::new(0, 0),
+            TouchPoint::new(10, 10),
+            TouchPoint::new(20, 20),
+        ];
+        assert_eq!(AffineCalibration::from_three_points(raw, raw), None);
+    }
+
+    #[test]
+    fn range_map_scales_to_panel() {
+        let map = RangeMap::from_ranges((0, 4095), (0, 4095), (800, 480));
+        assert_eq!(map.map(TouchPoint::new(0, 0)), TouchPoint::new(0, 0));
+        assert_eq!(
+            map.map(TouchPoint::new(4095, 4095)),
+            TouchPoint::new(799, 479)
+        );
+        assert_eq!(
+            map.map(TouchPoint::new(2048, 1024)),
+            TouchPoint::new(400, 120)
+        );
+    }
+
+    #[test]
+    fn range_map_inverted_axis() {
+        let map = RangeMap::from_ranges((100, 3900), (3900, 100), (320, 240));
+        assert_eq!(map.map(TouchPoint::new(100, 3900)), TouchPoint::new(0, 0));
+        assert_eq!(
+            map.map(TouchPoint::new(3900, 100)),
+            TouchPoint::new(319, 239)
+        );
+        assert_eq!(
+            map.map(TouchPoint::new(2000, 2000)),
+            TouchPoint::new(160, 120)
+        );
+    }
+
+    #[test]
+    fn range_map_clamps_out_of_range() {
+        let map = RangeMap::from_ranges((100, 3900), (3900, 100), (320, 240));
+        assert_eq!(map.map(TouchPoint::new(-50, 5000)), TouchPoint::new(0, 0));
+        assert_eq!(
+            map.map(TouchPoint::new(i32::MAX, i32::MIN)),
+            TouchPoint::new(319, 239)
+        );
+    }
+}
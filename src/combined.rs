@@ -0,0 +1,159 @@
+//! Merging of two touch devices into one
+
+use core::fmt;
+
+use crate::{Touch, id_map::IdMap, traits::TouchInputDevice};
+
+/// Offset added to the touch IDs of the second device of a [`Combined`]
+pub const SECOND_ID_OFFSET: u8 = 128;
+
+/// A touch device that reports the touches of two devices together
+///
+/// Each read reads the first device, then the second, and fails if either fails.
+///
+/// To keep IDs from colliding, IDs `0..128` of the first device are passed through
+/// unchanged, and IDs `0..128` of the second device are offset by [`SECOND_ID_OFFSET`]
+/// into `128..=255`. Touches with IDs of 128 or above cannot be remapped without
+/// colliding, so they are dropped.
+///
+/// Up to `N` touches are buffered per read. Additional touches are dropped.
+#[derive(Debug)]
+pub struct Combined<A, B, const N: usize> {
+    first: A,
+    second: B,
+    buffer: IdMap<Touch, N>,
+}
+
+impl<A, B, const N: usize> Combined<A, B, N> {
+    /// Combine two devices
+    #[must_use]
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            buffer: IdMap::new(),
+        }
+    }
+
+    /// Returns references to the wrapped devices
+    pub fn devices(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+
+    /// Returns mutable references to the wrapped devices
+    pub fn devices_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.first, &mut self.second)
+    }
+
+    /// Consumes the adapter, returning the wrapped devices
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn push<'a>(
+        buffer: &mut IdMap<Touch, N>,
+        touches: impl IntoIterator<Item = &'a Touch>,
+        offset: u8,
+    ) {
+        for touch in touches {
+            if touch.id >= SECOND_ID_OFFSET {
+                continue;
+            }
+            let mut touch = touch.clone();
+            touch.id += offset;
+            // Touches beyond capacity are dropped
+            _ = buffer.insert(touch.id, touch);
+        }
+    }
+}
+
+impl<A: TouchInputDevice, B: TouchInputDevice, const N: usize> TouchInputDevice
+    for Combined<A, B, N>
+{
+    type Error = CombinedError<A::Error, B::Error>;
+
+    const MAX_TOUCHES: usize = {
+        let total = A::MAX_TOUCHES.saturating_add(B::MAX_TOUCHES);
+        if total < N { total } else { N }
+    };
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        self.buffer.clear();
+        let touches = self.first.touches().map_err(CombinedError::First)?;
+        Self::push(&mut self.buffer, touches, 0);
+        let touches = self.second.touches().map_err(CombinedError::Second)?;
+        Self::push(&mut self.buffer, touches, SECOND_ID_OFFSET);
+        Ok(self.buffer.values())
+    }
+}
+
+/// Error returned by [`Combined`] when one of its devices fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CombinedError<A, B> {
+    /// The first device failed
+    First(A),
+    /// The second device failed
+    Second(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for CombinedError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CombinedError::First(error) => write!(f, "first device: {error}"),
+            CombinedError::Second(error) => write!(f, "second device: {error}"),
+        }
+    }
+}
+
+impl<A: core::error::Error, B: core::error::Error> core::error::Error for CombinedError<A, B> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Phase,
+        test_util::{Frames, finger},
+    };
+
+    #[test]
+    fn merges_touches_with_remapped_ids() {
+        let screen: &[&[Touch]] = &[
+            &[
+                finger(0, 10, 0, Phase::Moved),
+                finger(1, 20, 0, Phase::Moved),
+            ],
+            &[finger(1, 25, 0, Phase::Moved)],
+        ];
+        let trackpad: &[&[Touch]] = &[
+            &[
+                finger(0, 500, 0, Phase::Moved),
+                finger(200, 0, 0, Phase::Moved),
+            ],
+            &[],
+        ];
+        let mut combined = Combined::<_, _, 4>::new(Frames::new(screen), Frames::new(trackpad));
+
+        let ids = |combined: &mut Combined<Frames<'_>, Frames<'_>, 4>| {
+            let mut ids = [None; 4];
+            for (slot, touch) in ids.iter_mut().zip(combined.touches().unwrap()) {
+                *slot = Some((touch.id, touch.location.x));
+            }
+            ids
+        };
+        assert_eq!(
+            ids(&mut combined),
+            [Some((0, 10)), Some((1, 20)), Some((128, 500)), None]
+        );
+        assert_eq!(ids(&mut combined), [Some((1, 25)), None, None, None]);
+
+        assert_eq!(combined.touches().err(), Some(CombinedError::First(())));
+    }
+
+    #[test]
+    fn reports_second_device_errors() {
+        let screen: &[&[Touch]] = &[&[finger(0, 10, 0, Phase::Moved)]];
+        let mut combined = Combined::<_, _, 4>::new(Frames::new(screen), Frames::new(&[]));
+        assert_eq!(combined.touches().err(), Some(CombinedError::Second(())));
+    }
+}
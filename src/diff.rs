@@ -0,0 +1,189 @@
+//! Per-touch differences between two frames
+
+use crate::{Touch, id_map::IdMap};
+
+/// Compare two frames of touches by ID
+///
+/// See [`TouchDiff`] for how touches are classified.
+#[must_use]
+pub fn diff<'a>(prev: &'a [Touch], curr: &'a [Touch]) -> TouchDiff<'a> {
+    TouchDiff { prev, curr }
+}
+
+/// The differences between two frames of touches, matched by ID
+///
+/// The lists are computed lazily from the borrowed frames, so no storage is needed.
+/// Touches are returned in the order they appear in their frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TouchDiff<'a> {
+    prev: &'a [Touch],
+    curr: &'a [Touch],
+}
+
+impl<'a> TouchDiff<'a> {
+    /// Returns the touches in the current frame whose ID was not in the previous frame
+    pub fn added(&self) -> impl Iterator<Item = &'a Touch> + use<'a> {
+        let prev = self.prev;
+        self.curr
+            .iter()
+            .filter(move |touch| find(prev, touch.id).is_none())
+    }
+
+    /// Returns the touches in the current frame whose location or phase changed
+    ///
+    /// A touch that ended in the current frame is reported here, since its phase
+    /// changed, rather than as removed.
+    pub fn moved(&self) -> impl Iterator<Item = &'a Touch> + use<'a> {
+        let prev = self.prev;
+        self.curr.iter().filter(move |touch| {
+            find(prev, touch.id)
+                .is_some_and(|old| old.location != touch.location || old.phase != touch.phase)
+        })
+    }
+
+    /// Returns the touches in the previous frame whose ID is not in the current frame
+    pub fn removed(&self) -> impl Iterator<Item = &'a Touch> + use<'a> {
+        let curr = self.curr;
+        self.prev
+            .iter()
+            .filter(move |touch| find(curr, touch.id).is_none())
+    }
+
+    /// Returns `true` if nothing was added, moved, or removed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added().next().is_none()
+            && self.moved().next().is_none()
+            && self.removed().next().is_none()
+    }
+}
+
+/// Passes only the touches that changed since they were last seen
+///
+/// Each touch is compared by ID to the last value passed for that ID, and is suppressed
+/// if every field is unchanged. Touches in a terminal phase, as reported by
+/// [`Phase::is_terminal`](crate::Phase::is_terminal), always pass, and their state is
+/// forgotten. This suits forwarding touches over bandwidth-limited links.
+///
+/// Up to `N` touches are tracked; additional touches always pass.
+#[derive(Debug, Clone)]
+pub struct ChangeFilter<const N: usize> {
+    previous: IdMap<Touch, N>,
+}
+
+impl<const N: usize> ChangeFilter<N> {
+    /// Create a filter with no touches seen
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            previous: IdMap::new(),
+        }
+    }
+
+    /// Returns the touches that changed since the previous call
+    ///
+    /// The state is updated as the returned iterator is consumed, so touches that are not
+    /// iterated over are not recorded.
+    pub fn filter<'a>(
+        &mut self,
+        touches: impl Iterator<Item = &'a Touch>,
+    ) -> impl Iterator<Item = &'a Touch> {
+        touches.filter(|touch| {
+            if touch.phase.is_terminal() {
+                self.previous.remove(touch.id);
+                return true;
+            }
+            if self.previous.get(touch.id) == Some(*touch) {
+                return false;
+            }
+            _ = self.previous.insert(touch.id, (*touch).clone());
+            true
+        })
+    }
+
+    /// Forget all touches seen, e.g. after the connection to the device is reset
+    pub fn clear(&mut self) {
+        self.previous.clear();
+    }
+}
+
+impl<const N: usize> Default for ChangeFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn find(touches: &[Touch], id: u8) -> Option<&Touch> {
+    touches.iter().find(|touch| touch.id == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, test_util::finger};
+
+    fn ids<'a>(touches: impl Iterator<Item = &'a Touch>) -> u8 {
+        touches.fold(0, |mask, touch| mask | 1 << touch.id)
+    }
+
+    #[test]
+    fn classifies_added_moved_and_removed() {
+        let prev = [
+            finger(0, 10, 0, Phase::Moved),
+            finger(1, 20, 0, Phase::Moved),
+            finger(2, 30, 0, Phase::Moved),
+        ];
+        let curr = [
+            finger(0, 10, 0, Phase::Moved),
+            finger(1, 25, 0, Phase::Moved),
+            finger(3, 40, 0, Phase::Started),
+        ];
+        let diff = diff(&prev, &curr);
+
+        assert_eq!(ids(diff.added()), 1 << 3);
+        assert_eq!(ids(diff.moved()), 1 << 1);
+        assert_eq!(ids(diff.removed()), 1 << 2);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn change_filter_suppresses_unchanged_touches() {
+        let mut filter = ChangeFilter::<4>::new();
+        let first = [
+            finger(0, 10, 0, Phase::Started),
+            finger(1, 20, 0, Phase::Started),
+        ];
+        assert_eq!(ids(filter.filter(first.iter())), 0b11);
+
+        let second = [
+            finger(0, 10, 0, Phase::Moved),
+            finger(1, 20, 0, Phase::Moved),
+        ];
+        assert_eq!(ids(filter.filter(second.iter())), 0b11, "phases changed");
+        let third = [
+            finger(0, 10, 0, Phase::Moved),
+            finger(1, 25, 0, Phase::Moved),
+        ];
+        assert_eq!(ids(filter.filter(third.iter())), 0b10);
+        assert_eq!(ids(filter.filter(third.iter())), 0);
+
+        let fourth = [
+            finger(0, 10, 0, Phase::Ended),
+            finger(1, 25, 0, Phase::Moved),
+        ];
+        assert_eq!(ids(filter.filter(fourth.iter())), 0b01);
+        assert_eq!(
+            ids(filter.filter(fourth.iter())),
+            0b01,
+            "terminal phases always pass"
+        );
+    }
+
+    #[test]
+    fn phase_change_counts_as_moved() {
+        let prev = [finger(0, 10, 0, Phase::Moved)];
+        let curr = [finger(0, 10, 0, Phase::Ended)];
+        assert_eq!(ids(diff(&prev, &curr).moved()), 1);
+        assert!(diff(&prev, &prev).is_empty());
+    }
+}
@@ -0,0 +1,366 @@
+//! Compact fixed-size binary encoding of touches, e.g. for logging to flash
+//!
+//! A touch is encoded into [`Touch::ENCODED_LEN`] bytes, with multi-byte fields in
+//! little-endian order:
+//!
+//! | Bytes | Field                                                                |
+//! |-------|----------------------------------------------------------------------|
+//! | 0     | ID                                                                   |
+//! | 1     | Phase tag: 0 started, 1 moved, 2 ended, 3 cancelled, 4 hovering,     |
+//! |       | 5 stationary                                                         |
+//! | 2–3   | Hover proximity                                                      |
+//! | 4–7   | X, [`i32`]                                                           |
+//! | 8–11  | Y, [`i32`]                                                           |
+//! | 12    | Tool tag: 0 finger, 1 pointer, 2 stylus, 3 contact, 4 eraser         |
+//! | 13    | Presence bitmask: bit 0 proximity, 1 pressure, 2 tilt, 3 azimuth,    |
+//! |       | 4 barrel pressure, 5 wheel delta                                     |
+//! | 14–15 | Pointer button, stylus or eraser pressure, or contact major axis     |
+//! | 16–17 | Horizontal wheel delta, raw stylus or eraser tilt, or contact minor  |
+//! |       | axis                                                                 |
+//! | 18–19 | Vertical wheel delta, or raw stylus or eraser azimuth                |
+//! | 20–21 | Stylus barrel pressure                                               |
+//!
+//! Pointer buttons are encoded with [`PointerButton::to_index`], and wheel deltas as
+//! [`i16`].
+//! Fields that do not apply to the phase or tool, or whose presence bit is clear, are
+//! encoded as zero, and decoding rejects them if they are not.
+
+use core::fmt;
+
+use crate::{Phase, PointerButton, Tool, Touch, TouchPoint, UnitAngle};
+
+const PROXIMITY: u8 = 1 << 0;
+const PRESSURE: u8 = 1 << 1;
+const TILT: u8 = 1 << 2;
+const AZIMUTH: u8 = 1 << 3;
+const BARREL_PRESSURE: u8 = 1 << 4;
+const WHEEL: u8 = 1 << 5;
+const ERASER_FIELDS: u8 = PRESSURE | TILT | AZIMUTH;
+const STYLUS_FIELDS: u8 = ERASER_FIELDS | BARREL_PRESSURE;
+
+impl Touch {
+    /// Length of the encoding produced by [`Touch::to_bytes`]
+    pub const ENCODED_LEN: usize = 22;
+
+    /// Encode the touch into its compact binary form
+    ///
+    /// See the [module documentation](crate::encoding) for the layout.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Touch::ENCODED_LEN] {
+        let mut bytes = [0; Touch::ENCODED_LEN];
+        let mut presence = 0;
+
+        bytes[0] = self.id;
+        bytes[1] = match self.phase {
+            Phase::Started => 0,
+            Phase::Moved => 1,
+            Phase::Ended => 2,
+            Phase::Cancelled => 3,
+            Phase::Hovering(proximity) => {
+                if let Some(proximity) = proximity {
+                    presence |= PROXIMITY;
+                    bytes[2..4].copy_from_slice(&proximity.to_le_bytes());
+                }
+                4
+            }
+            Phase::Stationary => 5,
+        };
+        bytes[4..8].copy_from_slice(&self.location.x.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.location.y.to_le_bytes());
+
+        let mut fields = [0u16; 4];
+        bytes[12] = match self.tool {
+            Tool::Finger => 0,
+            Tool::Pointer {
+                button,
+                wheel_delta,
+            } => {
+                fields[0] = button.to_index().into();
+                if let Some((horizontal, vertical)) = wheel_delta {
+                    presence |= WHEEL;
+                    fields[1] = horizontal.cast_unsigned();
+                    fields[2] = vertical.cast_unsigned();
+                }
+                1
+            }
+            Tool::Stylus { .. } | Tool::Eraser { .. } => {
+                let tool = &self.tool;
+                for (field, bit, value) in [
+                    (0, PRESSURE, tool.pressure()),
+                    (1, TILT, tool.tilt().map(|tilt| tilt.as_raw())),
+                    (2, AZIMUTH, tool.azimuth().map(|azimuth| azimuth.as_raw())),
+                    (3, BARREL_PRESSURE, tool.barrel_pressure()),
+                ] {
+                    if let Some(value) = value {
+                        presence |= bit;
+                        fields[field] = value;
+                    }
+                }
+                if tool.is_eraser() { 4 } else { 2 }
+            }
+            Tool::Contact {
+                major_axis,
+                minor_axis,
+            } => {
+                fields[0] = major_axis;
+                fields[1] = minor_axis;
+                3
+            }
+        };
+        bytes[13] = presence;
+        for (chunk, field) in bytes[14..].chunks_exact_mut(2).zip(fields) {
+            chunk.copy_from_slice(&field.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a touch from the form produced by [`Touch::to_bytes`]
+    pub fn from_bytes(bytes: &[u8; Touch::ENCODED_LEN]) -> Result<Touch, DecodeError> {
+        let word = |offset: usize| u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let long = |offset: usize| {
+            i32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ])
+        };
+        let presence = bytes[13];
+        let present = |bit: u8| presence & bit != 0;
+
+        let phase = match bytes[1] {
+            0 => Phase::Started,
+            1 => Phase::Moved,
+            2 => Phase::Ended,
+            3 => Phase::Cancelled,
+            4 => Phase::Hovering(present(PROXIMITY).then(|| word(2))),
+            5 => Phase::Stationary,
+            tag => return Err(DecodeError::InvalidPhase(tag)),
+        };
+        let tool = match bytes[12] {
+            0 => Tool::Finger,
+            1 => Tool::Pointer {
+                button: u8::try_from(word(14))
+                    .ok()
+                    .and_then(PointerButton::from_index)
+                    .ok_or(DecodeError::InvalidButton(word(14)))?,
+                wheel_delta: present(WHEEL)
+                    .then(|| (word(16).cast_signed(), word(18).cast_signed())),
+            },
+            2 => Tool::Stylus {
+                pressure: present(PRESSURE).then(|| word(14)),
+                barrel_pressure: present(BARREL_PRESSURE).then(|| word(20)),
+                tilt: present(TILT).then(|| UnitAngle::from_raw(word(16))),
+                azimuth: present(AZIMUTH).then(|| UnitAngle::from_raw(word(18))),
+            },
+            3 => Tool::Contact {
+                major_axis: word(14),
+                minor_axis: word(16),
+            },
+            4 => Tool::Eraser {
+                pressure: present(PRESSURE).then(|| word(14)),
+                tilt: present(TILT).then(|| UnitAngle::from_raw(word(16))),
+                azimuth: present(AZIMUTH).then(|| UnitAngle::from_raw(word(18))),
+            },
+            tag => return Err(DecodeError::InvalidTool(tag)),
+        };
+
+        let allowed = match (phase, tool) {
+            (Phase::Hovering(_), Tool::Stylus { .. }) => PROXIMITY | STYLUS_FIELDS,
+            (Phase::Hovering(_), Tool::Eraser { .. }) => PROXIMITY | ERASER_FIELDS,
+            (Phase::Hovering(_), Tool::Pointer { .. }) => PROXIMITY | WHEEL,
+            (Phase::Hovering(_), _) => PROXIMITY,
+            (_, Tool::Stylus { .. }) => STYLUS_FIELDS,
+            (_, Tool::Eraser { .. }) => ERASER_FIELDS,
+            (_, Tool::Pointer { .. }) => WHEEL,
+            _ => 0,
+        };
+        if presence & !allowed != 0 {
+            return Err(DecodeError::InvalidPresence(presence));
+        }
+
+        let touch = Touch::new(bytes[0], TouchPoint::new(long(4), long(8)), phase, tool);
+        // The encoding is canonical, so any other difference is a reserved byte
+        if let Some(offset) = touch
+            .to_bytes()
+            .iter()
+            .zip(bytes)
+            .position(|(canonical, byte)| canonical != byte)
+        {
+            return Err(DecodeError::NonZeroReserved(offset));
+        }
+        Ok(touch)
+    }
+}
+
+/// Error returned by [`Touch::from_bytes`] for malformed input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DecodeError {
+    /// The phase tag is not recognized
+    InvalidPhase(u8),
+    /// The tool tag is not recognized
+    InvalidTool(u8),
+    /// The pointer button is not recognized
+    InvalidButton(u16),
+    /// The presence bitmask has bits set that do not apply to the phase or tool
+    InvalidPresence(u8),
+    /// The byte at this offset belongs to a field that does not apply or is absent, but
+    /// is not zero
+    NonZeroReserved(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidPhase(tag) => write!(f, "invalid phase tag {tag}"),
+            DecodeError::InvalidTool(tag) => write!(f, "invalid tool tag {tag}"),
+            DecodeError::InvalidButton(button) => write!(f, "invalid pointer button {button}"),
+            DecodeError::InvalidPresence(bits) => write!(f, "invalid presence bits {bits:#04x}"),
+            DecodeError::NonZeroReserved(offset) => {
+                write!(f, "reserved byte {offset} is not zero")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(touch: &Touch) {
+        let bytes = touch.to_bytes();
+        assert_eq!(Touch::from_bytes(&bytes).as_ref(), Ok(touch), "{bytes:?}");
+    }
+
+    #[test]
+    fn round_trips_each_tool() {
+        let location = TouchPoint::new(-123_456, 7_890_123);
+        let tools = [
+            Tool::Finger,
+            Tool::Pointer {
+                button: PointerButton::Secondary,
+                wheel_delta: None,
+            },
+            Tool::Pointer {
+                button: PointerButton::None,
+                wheel_delta: Some((-1, i16::MIN)),
+            },
+            Tool::Stylus {
+                pressure: Some(512),
+                barrel_pressure: None,
+                tilt: None,
+                azimuth: Some(UnitAngle::from_raw(0xABCD)),
+            },
+            Tool::Stylus {
+                pressure: None,
+                barrel_pressure: Some(0x0F0E),
+                tilt: Some(UnitAngle::from_raw(0x1234)),
+                azimuth: None,
+            },
+            Tool::Contact {
+                major_axis: 40,
+                minor_axis: 25,
+            },
+            Tool::Eraser {
+                pressure: Some(900),
+                tilt: Some(UnitAngle::from_raw(0x2000)),
+                azimuth: None,
+            },
+        ];
+        let phases = [
+            Phase::Started,
+            Phase::Moved,
+            Phase::Ended,
+            Phase::Cancelled,
+            Phase::Hovering(None),
+            Phase::Hovering(Some(0xFEDC)),
+            Phase::Stationary,
+        ];
+        for tool in tools {
+            for phase in phases {
+                round_trip(&Touch::new(42, location, phase, tool));
+            }
+        }
+    }
+
+    #[test]
+    fn layout_is_little_endian() {
+        let touch = Touch::new(
+            7,
+            TouchPoint::new(0x0102_0304, -2),
+            Phase::Moved,
+            Tool::Contact {
+                major_axis: 0x0A0B,
+                minor_axis: 0x0C0D,
+            },
+        );
+        assert_eq!(
+            touch.to_bytes(),
+            [
+                7, 1, 0, 0, 0x04, 0x03, 0x02, 0x01, 0xFE, 0xFF, 0xFF, 0xFF, 3, 0, 0x0B, 0x0A, 0x0D,
+                0x0C, 0, 0, 0, 0
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let valid = Touch::new(0, TouchPoint::new(1, 2), Phase::Started, Tool::Finger).to_bytes();
+
+        let mut bytes = valid;
+        bytes[1] = 9;
+        assert_eq!(Touch::from_bytes(&bytes), Err(DecodeError::InvalidPhase(9)));
+
+        let mut bytes = valid;
+        bytes[12] = 200;
+        assert_eq!(
+            Touch::from_bytes(&bytes),
+            Err(DecodeError::InvalidTool(200))
+        );
+
+        let mut bytes = valid;
+        bytes[12] = 1;
+        bytes[14] = 4;
+        assert_eq!(
+            Touch::from_bytes(&bytes),
+            Err(DecodeError::InvalidButton(4))
+        );
+
+        let mut bytes = valid;
+        bytes[13] = PRESSURE;
+        assert_eq!(
+            Touch::from_bytes(&bytes),
+            Err(DecodeError::InvalidPresence(PRESSURE))
+        );
+
+        // Proximity of a touch that is not hovering
+        let mut bytes = valid;
+        bytes[3] = 1;
+        assert_eq!(
+            Touch::from_bytes(&bytes),
+            Err(DecodeError::NonZeroReserved(3))
+        );
+
+        // Fields a finger does not use
+        for offset in 14..Touch::ENCODED_LEN {
+            let mut bytes = valid;
+            bytes[offset] = 0x80;
+            assert_eq!(
+                Touch::from_bytes(&bytes),
+                Err(DecodeError::NonZeroReserved(offset))
+            );
+        }
+
+        // Stylus pressure whose presence bit is clear
+        let stylus = Touch::stylus(0, TouchPoint::new(1, 2), None, Phase::Moved);
+        let mut bytes = stylus.to_bytes();
+        bytes[14] = 1;
+        assert_eq!(
+            Touch::from_bytes(&bytes),
+            Err(DecodeError::NonZeroReserved(14))
+        );
+    }
+}
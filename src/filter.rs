@@ -0,0 +1,114 @@
+//! Device adapters that filter or modify touches as they are read
+//!
+//! Each filter implements [`TouchFilter`], and is applied to a device by wrapping it in
+//! a [`Filtered`] adapter.
+
+mod debounce;
+mod edge;
+mod hover;
+mod palm;
+mod smooth;
+mod stationary;
+
+pub use debounce::{Debounce, Debounced};
+pub use edge::{EdgeFilter, EdgeReject};
+pub use hover::{HoverHysteresis, HoverStabilized};
+pub use palm::{PalmFilter, PalmRejection};
+pub use smooth::{Smooth, Smoothed};
+pub use stationary::{StationaryDetection, StationaryFilter};
+
+use crate::{
+    Touch,
+    id_map::IdMap,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// Stateful processing applied to each touch read from a device
+pub trait TouchFilter {
+    /// Process a single touch, returning the touch to report or `None` to drop it
+    fn filter(&mut self, touch: &Touch) -> Option<Touch>;
+}
+
+/// A touch device adapter that applies a [`TouchFilter`] to every touch
+///
+/// Up to `N` touches are buffered per read. Additional touches are dropped.
+#[derive(Debug)]
+pub struct Filtered<D, F, const N: usize> {
+    device: D,
+    filter: F,
+    buffer: IdMap<Touch, N>,
+}
+
+impl<D, F: TouchFilter, const N: usize> Filtered<D, F, N> {
+    /// Wrap a device, applying the filter to each of its touches
+    #[must_use]
+    pub fn new(device: D, filter: F) -> Self {
+        Self {
+            device,
+            filter,
+            buffer: IdMap::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped device
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the wrapped device
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Returns a reference to the filter
+    pub fn filter(&self) -> &F {
+        &self.filter
+    }
+
+    /// Consumes the adapter, returning the wrapped device
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    fn fill<'a>(
+        buffer: &mut IdMap<Touch, N>,
+        filter: &mut F,
+        touches: impl IntoIterator<Item = &'a Touch>,
+    ) {
+        buffer.clear();
+        for touch in touches {
+            if let Some(touch) = filter.filter(touch) {
+                // Touches beyond capacity are dropped
+                _ = buffer.insert(touch.id, touch);
+            }
+        }
+    }
+}
+
+impl<D: TouchInputDevice, F: TouchFilter, const N: usize> TouchInputDevice for Filtered<D, F, N> {
+    type Error = D::Error;
+
+    const MAX_TOUCHES: usize = if D::MAX_TOUCHES < N {
+        D::MAX_TOUCHES
+    } else {
+        N
+    };
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        let touches = self.device.touches()?;
+        Self::fill(&mut self.buffer, &mut self.filter, touches);
+        Ok(self.buffer.values())
+    }
+}
+
+impl<D: AsyncTouchInputDevice, F: TouchFilter, const N: usize> AsyncTouchInputDevice
+    for Filtered<D, F, N>
+{
+    type Error = D::Error;
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        let touches = self.device.touches().await?;
+        Self::fill(&mut self.buffer, &mut self.filter, touches);
+        Ok(self.buffer.values())
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// Suppresses jitter by dropping small movements
+///
+/// [`Phase::Moved`] touches closer than the threshold to the last reported location of
+/// the same touch are dropped from the frame. All other phases are always reported.
+/// Up to `N` touches are tracked; movements of additional touches are not filtered.
+#[derive(Debug, Clone)]
+pub struct Debounce<const N: usize> {
+    threshold: u32,
+    reported: IdMap<TouchPoint, N>,
+}
+
+impl<const N: usize> Debounce<N> {
+    /// Create a debounce filter that drops movements smaller than `threshold` pixels
+    #[must_use]
+    pub fn new(threshold: u32) -> Self {
+        Self {
+            threshold,
+            reported: IdMap::new(),
+        }
+    }
+}
+
+impl<const N: usize> TouchFilter for Debounce<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        match touch.phase {
+            Phase::Started => {
+                _ = self.reported.insert(touch.id, touch.location);
+            }
+            Phase::Moved => {
+                if let Some(reported) = self.reported.get_mut(touch.id) {
+                    let threshold = i64::from(self.threshold);
+                    if reported.distance_squared(&touch.location) < threshold * threshold {
+                        return None;
+                    }
+                    *reported = touch.location;
+                } else {
+                    _ = self.reported.insert(touch.id, touch.location);
+                }
+            }
+            Phase::Ended | Phase::Cancelled => {
+                self.reported.remove(touch.id);
+            }
+            Phase::Stationary | Phase::Hovering(_) => {}
+        }
+        Some(touch.clone())
+    }
+}
+
+/// A touch device adapter that suppresses jitter, see [`Debounce`]
+pub type Debounced<D, const N: usize> = Filtered<D, Debounce<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{Frames, block_on, finger},
+        traits::{AsyncTouchInputDevice, TouchInputDevice},
+    };
+
+    #[test]
+    fn filters_jitter_but_passes_real_moves() {
+        let mut debounce = Debounce::<2>::new(3);
+        assert!(
+            debounce
+                .filter(&finger(0, 100, 0, Phase::Started))
+                .is_some()
+        );
+        assert_eq!(debounce.filter(&finger(0, 101, 0, Phase::Moved)), None);
+        assert_eq!(debounce.filter(&finger(0, 98, 0, Phase::Moved)), None);
+        assert_eq!(
+            debounce.filter(&finger(0, 103, 0, Phase::Moved)),
+            Some(finger(0, 103, 0, Phase::Moved))
+        );
+        assert_eq!(debounce.filter(&finger(0, 104, 0, Phase::Moved)), None);
+        assert_eq!(
+            debounce.filter(&finger(0, 104, 0, Phase::Ended)),
+            Some(finger(0, 104, 0, Phase::Ended))
+        );
+    }
+
+    #[test]
+    fn tracks_touches_independently() {
+        let mut debounce = Debounce::<2>::new(3);
+        debounce.filter(&finger(0, 100, 0, Phase::Started));
+        debounce.filter(&finger(1, 200, 0, Phase::Started));
+        assert_eq!(debounce.filter(&finger(0, 101, 0, Phase::Moved)), None);
+        assert!(debounce.filter(&finger(1, 205, 0, Phase::Moved)).is_some());
+        assert!(
+            debounce
+                .filter(&finger(1, 205, 0, Phase::Cancelled))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn debounced_device_drops_jittering_touches() {
+        let frames: &[&[Touch]] = &[
+            &[
+                finger(0, 10, 0, Phase::Started),
+                finger(1, 50, 0, Phase::Started),
+            ],
+            &[
+                finger(0, 11, 0, Phase::Moved),
+                finger(1, 60, 0, Phase::Moved),
+            ],
+        ];
+        let mut device = Debounced::<_, 4>::new(Frames::new(frames), Debounce::new(3));
+
+        let touches = TouchInputDevice::touches(&mut device).unwrap();
+        assert_eq!(touches.into_iter().count(), 2);
+        let touches = block_on(AsyncTouchInputDevice::touches(&mut device)).unwrap();
+        assert!(touches.into_iter().eq([&finger(1, 60, 0, Phase::Moved)]));
+    }
+}
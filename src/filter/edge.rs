@@ -0,0 +1,102 @@
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// Rejects touches that start near the edge of the panel, such as bezel contacts
+///
+/// A touch that starts within `margin` pixels of any edge is reported once as
+/// [`Phase::Cancelled`], and all subsequent events for it are dropped until it ends.
+/// Touches that start inside the active area are passed through, even if they later
+/// move into the margin. Hovering touches are always passed through.
+///
+/// Up to `N` rejected touches are tracked; the later events of additional rejected
+/// touches are passed through.
+#[derive(Debug, Clone)]
+pub struct EdgeFilter<const N: usize> {
+    width: u32,
+    height: u32,
+    margin: u32,
+    rejected: IdMap<(), N>,
+}
+
+impl<const N: usize> EdgeFilter<N> {
+    /// Create an edge filter for a `width` × `height` pixel panel
+    #[must_use]
+    pub fn new(width: u32, height: u32, margin: u32) -> Self {
+        Self {
+            width,
+            height,
+            margin,
+            rejected: IdMap::new(),
+        }
+    }
+
+    fn in_margin(&self, point: TouchPoint) -> bool {
+        let near_edge = |value: i32, size: u32| {
+            let value = i64::from(value);
+            let margin = i64::from(self.margin);
+            value < margin || value >= i64::from(size) - margin
+        };
+        near_edge(point.x, self.width) || near_edge(point.y, self.height)
+    }
+}
+
+impl<const N: usize> TouchFilter for EdgeFilter<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        let rejected = self.rejected.get(touch.id).is_some();
+        match touch.phase {
+            Phase::Started if self.in_margin(touch.location) => {
+                _ = self.rejected.insert(touch.id, ());
+                let mut touch = touch.clone();
+                touch.phase = Phase::Cancelled;
+                Some(touch)
+            }
+            Phase::Started => {
+                self.rejected.remove(touch.id);
+                Some(touch.clone())
+            }
+            Phase::Ended | Phase::Cancelled if rejected => {
+                self.rejected.remove(touch.id);
+                None
+            }
+            Phase::Moved | Phase::Stationary if rejected => None,
+            _ => Some(touch.clone()),
+        }
+    }
+}
+
+/// A touch device adapter that rejects touches starting near the panel edge, see
+/// [`EdgeFilter`]
+pub type EdgeReject<D, const N: usize> = Filtered<D, EdgeFilter<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn edge_touch_is_rejected() {
+        let mut edge = EdgeFilter::<2>::new(800, 480, 10);
+        assert_eq!(
+            edge.filter(&finger(0, 795, 200, Phase::Started)),
+            Some(finger(0, 795, 200, Phase::Cancelled))
+        );
+        assert_eq!(edge.filter(&finger(0, 400, 200, Phase::Moved)), None);
+        assert_eq!(edge.filter(&finger(0, 400, 200, Phase::Ended)), None);
+
+        for (x, y) in [(9, 200), (790, 200), (400, 5), (400, 470)] {
+            let rejected = edge.filter(&finger(1, x, y, Phase::Started));
+            assert_eq!(rejected.map(|touch| touch.phase), Some(Phase::Cancelled));
+        }
+    }
+
+    #[test]
+    fn interior_touch_may_drift_to_edge() {
+        let mut edge = EdgeFilter::<2>::new(800, 480, 10);
+        for (x, phase) in [(10, Phase::Started), (0, Phase::Moved), (0, Phase::Ended)] {
+            assert_eq!(
+                edge.filter(&finger(0, x, 200, phase)),
+                Some(finger(0, x, 200, phase))
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, id_map::IdMap};
+
+/// Stops touches chattering between hover and contact near the contact threshold
+///
+/// Hover proximity is treated as a distance, with smaller values nearer the screen, and
+/// contact phases from the device count as proximity 0. A hovering touch whose
+/// proximity drops to `enter` or below is reported as [`Phase::Started`], and a touch
+/// in contact is only reported as hovering again once its proximity rises above `exit`
+/// or is no longer reported. Until then, hover reports of a touch in contact become
+/// [`Phase::Moved`], and repeated [`Phase::Started`] reports from the device also
+/// become [`Phase::Moved`]. `exit` should be at least `enter`.
+///
+/// Up to `N` touches in contact are tracked; additional touches are passed through.
+#[derive(Debug, Clone)]
+pub struct HoverHysteresis<const N: usize> {
+    enter: u16,
+    exit: u16,
+    in_contact: IdMap<(), N>,
+}
+
+impl<const N: usize> HoverHysteresis<N> {
+    /// Create a filter that enters contact at proximity `enter` and leaves it above `exit`
+    #[must_use]
+    pub fn new(enter: u16, exit: u16) -> Self {
+        Self {
+            enter,
+            exit,
+            in_contact: IdMap::new(),
+        }
+    }
+}
+
+impl<const N: usize> TouchFilter for HoverHysteresis<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        let in_contact = self.in_contact.get(touch.id).is_some();
+        let mut touch = touch.clone();
+        match touch.phase {
+            Phase::Hovering(Some(proximity)) if in_contact && proximity <= self.exit => {
+                touch.phase = Phase::Moved;
+            }
+            Phase::Hovering(Some(proximity)) if !in_contact && proximity <= self.enter => {
+                if self.in_contact.insert(touch.id, ()).is_ok() {
+                    touch.phase = Phase::Started;
+                }
+            }
+            Phase::Started if in_contact => touch.phase = Phase::Moved,
+            Phase::Started | Phase::Moved | Phase::Stationary if !in_contact => {
+                if self.in_contact.insert(touch.id, ()).is_ok() {
+                    touch.phase = Phase::Started;
+                }
+            }
+            Phase::Hovering(_) | Phase::Ended | Phase::Cancelled => {
+                self.in_contact.remove(touch.id);
+            }
+            Phase::Started | Phase::Moved | Phase::Stationary => {}
+        }
+        Some(touch)
+    }
+}
+
+/// A touch device adapter that smooths hover-to-contact transitions, see
+/// [`HoverHysteresis`]
+pub type HoverStabilized<D, const N: usize> = Filtered<D, HoverHysteresis<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{Frames, finger},
+        traits::TouchInputDevice,
+    };
+
+    #[test]
+    fn oscillation_at_threshold_does_not_toggle() {
+        // The device switches to contact below a proximity of 12
+        let raw = [
+            Phase::Hovering(Some(12)),
+            Phase::Started,
+            Phase::Hovering(Some(12)),
+            Phase::Started,
+            Phase::Hovering(Some(13)),
+            Phase::Moved,
+            Phase::Hovering(Some(30)),
+            Phase::Hovering(Some(12)),
+            Phase::Hovering(Some(11)),
+        ];
+        let expected = [
+            Phase::Hovering(Some(12)),
+            Phase::Started,
+            Phase::Moved,
+            Phase::Moved,
+            Phase::Moved,
+            Phase::Moved,
+            Phase::Hovering(Some(30)),
+            Phase::Hovering(Some(12)),
+            Phase::Hovering(Some(11)),
+        ];
+        let mut filter = HoverHysteresis::<2>::new(5, 20);
+        for (raw, expected) in raw.into_iter().zip(expected) {
+            let filtered = filter.filter(&finger(0, 10, 10, raw)).unwrap();
+            assert_eq!(filtered.phase, expected, "from {raw:?}");
+        }
+    }
+
+    #[test]
+    fn close_hover_enters_contact() {
+        let frames: &[&[Touch]] = &[
+            &[finger(0, 10, 10, Phase::Hovering(Some(8)))],
+            &[finger(0, 10, 10, Phase::Hovering(Some(4)))],
+            &[finger(0, 10, 10, Phase::Hovering(Some(6)))],
+            &[finger(0, 10, 10, Phase::Hovering(None))],
+            &[finger(0, 10, 10, Phase::Ended)],
+        ];
+        let mut device =
+            HoverStabilized::<_, 1>::new(Frames::new(frames), HoverHysteresis::new(5, 20));
+
+        let mut phases = [None; 5];
+        for phase in &mut phases {
+            *phase = device
+                .touches()
+                .unwrap()
+                .into_iter()
+                .next()
+                .map(|touch| touch.phase);
+        }
+        assert_eq!(
+            phases,
+            [
+                Some(Phase::Hovering(Some(8))),
+                Some(Phase::Started),
+                Some(Phase::Moved),
+                Some(Phase::Hovering(None)),
+                Some(Phase::Ended),
+            ]
+        );
+    }
+}
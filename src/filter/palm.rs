@@ -0,0 +1,124 @@
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, id_map::IdMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Reported,
+    Rejected,
+}
+
+/// Rejects palms and other large contacts
+///
+/// Touches with a [`Tool::Contact`](crate::Tool::Contact) major axis larger than the
+/// threshold are rejected. A touch that was already reported is sent once more as
+/// [`Phase::Cancelled`], and all subsequent events for it are dropped until it ends.
+/// Touches that are too large from the start are never reported. Touches without a
+/// contact size are always passed through.
+///
+/// Up to `N` touches are tracked; additional touches are only rejected while they are
+/// too large.
+#[derive(Debug, Clone)]
+pub struct PalmFilter<const N: usize> {
+    max_major_axis: u16,
+    states: IdMap<State, N>,
+}
+
+impl<const N: usize> PalmFilter<N> {
+    /// Create a palm filter for contacts larger than `max_major_axis` pixels
+    #[must_use]
+    pub fn new(max_major_axis: u16) -> Self {
+        Self {
+            max_major_axis,
+            states: IdMap::new(),
+        }
+    }
+}
+
+impl<const N: usize> TouchFilter for PalmFilter<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        if touch.phase.is_hovering() {
+            return Some(touch.clone());
+        }
+
+        let state = self.states.get(touch.id).copied();
+        if touch.phase.is_terminal() {
+            self.states.remove(touch.id);
+            return (state != Some(State::Rejected)).then(|| touch.clone());
+        }
+
+        let oversized = touch
+            .tool
+            .contact_size()
+            .is_some_and(|(major_axis, _)| major_axis > self.max_major_axis);
+        match (state, oversized) {
+            (Some(State::Rejected), _) => None,
+            (Some(State::Reported), true) => {
+                _ = self.states.insert(touch.id, State::Rejected);
+                let mut touch = touch.clone();
+                touch.phase = Phase::Cancelled;
+                Some(touch)
+            }
+            (None, true) => {
+                _ = self.states.insert(touch.id, State::Rejected);
+                None
+            }
+            (_, false) => {
+                _ = self.states.insert(touch.id, State::Reported);
+                Some(touch.clone())
+            }
+        }
+    }
+}
+
+/// A touch device adapter that rejects palms, see [`PalmFilter`]
+pub type PalmRejection<D, const N: usize> = Filtered<D, PalmFilter<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tool, TouchPoint};
+
+    fn contact(id: u8, major_axis: u16, phase: Phase) -> Touch {
+        let tool = Tool::Contact {
+            major_axis,
+            minor_axis: major_axis / 2,
+        };
+        Touch::new(id, TouchPoint::new(50, 50), phase, tool)
+    }
+
+    #[test]
+    fn oversized_contact_is_cancelled() {
+        let mut palm = PalmFilter::<2>::new(20);
+        assert!(palm.filter(&contact(0, 10, Phase::Started)).is_some());
+        assert_eq!(
+            palm.filter(&contact(0, 40, Phase::Moved)),
+            Some(contact(0, 40, Phase::Cancelled))
+        );
+        assert_eq!(palm.filter(&contact(0, 10, Phase::Moved)), None);
+        assert_eq!(palm.filter(&contact(0, 10, Phase::Ended)), None);
+
+        // The ID is free for reuse once the rejected touch ends
+        assert!(palm.filter(&contact(0, 10, Phase::Started)).is_some());
+    }
+
+    #[test]
+    fn oversized_from_start_is_never_reported() {
+        let mut palm = PalmFilter::<2>::new(20);
+        assert_eq!(palm.filter(&contact(1, 60, Phase::Started)), None);
+        assert_eq!(palm.filter(&contact(1, 15, Phase::Moved)), None);
+        assert_eq!(palm.filter(&contact(1, 15, Phase::Ended)), None);
+    }
+
+    #[test]
+    fn normal_finger_passes_through() {
+        let mut palm = PalmFilter::<2>::new(20);
+        for phase in [Phase::Started, Phase::Moved, Phase::Ended] {
+            assert_eq!(
+                palm.filter(&contact(2, 20, phase)),
+                Some(contact(2, 20, phase))
+            );
+        }
+        let finger = Touch::new(3, TouchPoint::new(0, 0), Phase::Started, Tool::Finger);
+        assert_eq!(palm.filter(&finger), Some(finger));
+    }
+}
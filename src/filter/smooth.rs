@@ -0,0 +1,108 @@
+use fixed::types::{I48F16, U0F16};
+
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// Low-pass filters touch locations with an exponential moving average
+///
+/// Each update moves the filtered location `alpha` of the way toward the reported
+/// location, so smaller values of `alpha` smooth more heavily at the cost of lag. The
+/// filter is reset to the reported location on [`Phase::Started`], and cleared on
+/// [`Phase::Ended`] and [`Phase::Cancelled`]. Hovering touches are not filtered.
+///
+/// Filtering is done with sub-pixel precision, and output locations are rounded to the
+/// nearest pixel, with halves rounded away from zero. Up to `N` touches are
+/// tracked; additional touches are passed through unfiltered.
+#[derive(Debug, Clone)]
+pub struct Smooth<const N: usize> {
+    alpha: U0F16,
+    filtered: IdMap<[I48F16; 2], N>,
+}
+
+impl<const N: usize> Smooth<N> {
+    /// Create a smoothing filter with the given weight for new samples
+    #[must_use]
+    pub fn new(alpha: U0F16) -> Self {
+        Self {
+            alpha,
+            filtered: IdMap::new(),
+        }
+    }
+
+    fn update(&mut self, id: u8, location: TouchPoint) -> TouchPoint {
+        let sample = [I48F16::from_num(location.x), I48F16::from_num(location.y)];
+        let Some(filtered) = self.filtered.get_mut(id) else {
+            _ = self.filtered.insert(id, sample);
+            return location;
+        };
+        let alpha = I48F16::from_num(self.alpha);
+        for (filtered, sample) in filtered.iter_mut().zip(sample) {
+            *filtered += (sample - *filtered) * alpha;
+        }
+        let [x, y] = filtered.map(|value| value.round().to_num::<i32>());
+        TouchPoint { x, y }
+    }
+}
+
+impl<const N: usize> TouchFilter for Smooth<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        let mut touch = touch.clone();
+        match touch.phase {
+            Phase::Started => {
+                _ = self.filtered.insert(
+                    touch.id,
+                    [touch.location.x, touch.location.y].map(I48F16::from_num),
+                );
+            }
+            Phase::Moved | Phase::Stationary => {
+                touch.location = self.update(touch.id, touch.location);
+            }
+            Phase::Ended | Phase::Cancelled => {
+                touch.location = self.update(touch.id, touch.location);
+                self.filtered.remove(touch.id);
+            }
+            Phase::Hovering(_) => {}
+        }
+        Some(touch)
+    }
+}
+
+/// A touch device adapter that smooths touch locations, see [`Smooth`]
+pub type Smoothed<D, const N: usize> = Filtered<D, Smooth<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tool;
+
+    fn touch(x: i32, phase: Phase) -> Touch {
+        Touch::new(0, TouchPoint::new(x, -x), phase, Tool::Finger)
+    }
+
+    #[test]
+    fn step_input_approaches_target() {
+        let mut smooth = Smooth::<2>::new(U0F16::from_num(0.5));
+        let started = smooth.filter(&touch(0, Phase::Started)).unwrap();
+        assert_eq!(started.location, TouchPoint::new(0, 0));
+
+        let expected = [50, 75, 88, 94, 97, 98, 99, 100];
+        for x in expected {
+            let moved = smooth.filter(&touch(100, Phase::Moved)).unwrap();
+            assert_eq!(moved.location, TouchPoint::new(x, -x));
+        }
+    }
+
+    #[test]
+    fn restarting_resets_filter() {
+        let mut smooth = Smooth::<2>::new(U0F16::from_num(0.25));
+        smooth.filter(&touch(0, Phase::Started));
+        smooth.filter(&touch(100, Phase::Moved));
+        let ended = smooth.filter(&touch(100, Phase::Ended)).unwrap();
+        assert_eq!(ended.location, TouchPoint::new(44, -44));
+
+        let started = smooth.filter(&touch(500, Phase::Started)).unwrap();
+        assert_eq!(started.location, TouchPoint::new(500, -500));
+        let moved = smooth.filter(&touch(600, Phase::Moved)).unwrap();
+        assert_eq!(moved.location, TouchPoint::new(525, -525));
+    }
+}
@@ -0,0 +1,94 @@
+use super::{Filtered, TouchFilter};
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// Reports held touches as [`Phase::Stationary`]
+///
+/// A [`Phase::Moved`] touch at the same location as the previous report of the same
+/// touch is reclassified as [`Phase::Stationary`]. This only helps with drivers that
+/// keep reporting held touches; a driver that goes quiet while a touch is held still
+/// looks the same as one that has stalled. Up to `N` touches are tracked; additional
+/// touches are passed through unchanged.
+#[derive(Debug, Clone)]
+pub struct StationaryFilter<const N: usize> {
+    locations: IdMap<TouchPoint, N>,
+}
+
+impl<const N: usize> StationaryFilter<N> {
+    /// Create a filter with no touches tracked
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            locations: IdMap::new(),
+        }
+    }
+}
+
+impl<const N: usize> Default for StationaryFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TouchFilter for StationaryFilter<N> {
+    fn filter(&mut self, touch: &Touch) -> Option<Touch> {
+        let mut touch = touch.clone();
+        if touch.phase.is_terminal() {
+            self.locations.remove(touch.id);
+        } else if touch.phase.is_active() {
+            let previous = self.locations.get(touch.id).copied();
+            if touch.phase == Phase::Moved && previous == Some(touch.location) {
+                touch.phase = Phase::Stationary;
+            }
+            _ = self.locations.insert(touch.id, touch.location);
+        }
+        Some(touch)
+    }
+}
+
+/// A touch device adapter that reports held touches as stationary, see
+/// [`StationaryFilter`]
+pub type StationaryDetection<D, const N: usize> = Filtered<D, StationaryFilter<N>, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{Frames, finger},
+        traits::TouchInputDevice,
+    };
+
+    #[test]
+    fn held_finger_is_resting() {
+        let frames: &[&[Touch]] = &[
+            &[finger(0, 10, 20, Phase::Started)],
+            &[finger(0, 10, 20, Phase::Moved)],
+            &[finger(0, 10, 20, Phase::Moved)],
+            &[finger(0, 15, 20, Phase::Moved)],
+            &[finger(0, 15, 20, Phase::Moved)],
+            &[finger(0, 15, 20, Phase::Ended)],
+        ];
+        let mut device =
+            StationaryDetection::<_, 1>::new(Frames::new(frames), StationaryFilter::new());
+
+        let mut phases = [None; 6];
+        for phase in &mut phases {
+            *phase = device
+                .touches()
+                .unwrap()
+                .into_iter()
+                .next()
+                .map(|t| t.phase);
+        }
+        assert_eq!(
+            phases.map(Option::unwrap),
+            [
+                Phase::Started,
+                Phase::Stationary,
+                Phase::Stationary,
+                Phase::Moved,
+                Phase::Stationary,
+                Phase::Ended,
+            ]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Fixed-capacity container for a frame of touches
+
+use crate::{Touch, transform::CoordinateTransform};
+
+/// A fixed-capacity set of touches reported together, e.g. from a single device read
+///
+/// Touches are stored in the order they are pushed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TouchFrame<const N: usize> {
+    touches: heapless::Vec<Touch, N>,
+}
+
+impl<const N: usize> TouchFrame<N> {
+    /// Create an empty frame
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            touches: heapless::Vec::new(),
+        }
+    }
+
+    /// Copies touches into a new frame, dropping any beyond its capacity
+    pub(crate) fn truncated<'a>(touches: impl IntoIterator<Item = &'a Touch>) -> Self {
+        let mut frame = Self::new();
+        for touch in touches {
+            if frame.push(touch.clone()).is_err() {
+                break;
+            }
+        }
+        frame
+    }
+
+    /// Add a touch to the frame
+    ///
+    /// Returns the rejected touch if the frame is full.
+    pub fn push(&mut self, touch: Touch) -> Result<(), Touch> {
+        self.touches.push(touch)
+    }
+
+    /// Returns an iterator over the touches in the frame
+    pub fn iter(&self) -> core::slice::Iter<'_, Touch> {
+        self.touches.iter()
+    }
+
+    /// Returns the touch with the given ID, if present
+    #[must_use]
+    pub fn get_by_id(&self, id: u8) -> Option<&Touch> {
+        self.touches.iter().find(|touch| touch.id == id)
+    }
+
+    /// Returns the number of touches in the frame
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.touches.len()
+    }
+
+    /// Returns the number of touches in contact with the screen
+    ///
+    /// See [`iter::active_count`](crate::iter::active_count).
+    #[must_use]
+    pub fn active_count(&self) -> usize {
+        crate::iter::active_count(self.iter())
+    }
+
+    /// Returns `true` if the frame contains no touches
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.touches.is_empty()
+    }
+
+    /// Returns the touches in the frame as a slice
+    #[must_use]
+    pub fn as_slice(&self) -> &[Touch] {
+        &self.touches
+    }
+
+    /// Returns a copy of the frame with every location passed through `transform`
+    ///
+    /// IDs, phases, and tools are unchanged, and touches stay in the same order.
+    #[must_use]
+    pub fn transformed(&self, transform: &impl CoordinateTransform) -> Self {
+        let mut frame = self.clone();
+        for touch in &mut frame.touches {
+            touch.location = transform.transform(touch.location);
+        }
+        frame
+    }
+
+    /// Removes all touches from the frame
+    pub fn clear(&mut self) {
+        self.touches.clear();
+    }
+}
+
+impl<const N: usize> Default for TouchFrame<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a TouchFrame<N> {
+    type Item = &'a Touch;
+    type IntoIter = core::slice::Iter<'a, Touch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, Tool, TouchPoint};
+
+    fn touch(id: u8) -> Touch {
+        Touch::new(id, TouchPoint::new(id, id), Phase::Started, Tool::Finger)
+    }
+
+    #[test]
+    fn push_beyond_capacity_returns_touch() {
+        let mut frame = TouchFrame::<2>::new();
+        assert_eq!(frame.push(touch(0)), Ok(()));
+        assert_eq!(frame.push(touch(1)), Ok(()));
+        assert_eq!(frame.push(touch(2)), Err(touch(2)));
+        assert_eq!(frame.len(), 2);
+        assert_eq!(frame.as_slice(), &[touch(0), touch(1)]);
+    }
+
+    #[test]
+    fn get_by_id() {
+        let mut frame = TouchFrame::<4>::new();
+        frame.push(touch(3)).unwrap();
+        frame.push(touch(7)).unwrap();
+        assert_eq!(frame.get_by_id(7), Some(&touch(7)));
+        assert_eq!(frame.get_by_id(3), Some(&touch(3)));
+        assert_eq!(frame.get_by_id(0), None);
+    }
+
+    #[test]
+    fn transformed_moves_only_locations() {
+        use crate::transform::{Rotation, RotationAngle};
+
+        let mut frame = TouchFrame::<2>::new();
+        frame.push(touch(3)).unwrap();
+        let mut moved = touch(7);
+        moved.phase = Phase::Moved;
+        frame.push(moved).unwrap();
+
+        let rotation = Rotation::new(RotationAngle::Deg90, 240, 320);
+        let rotated = frame.transformed(&rotation);
+        assert_eq!(rotated.len(), 2);
+        for (before, after) in frame.iter().zip(&rotated) {
+            assert_eq!(after.id, before.id);
+            assert_eq!(after.phase, before.phase);
+            assert_eq!(after.tool, before.tool);
+            assert_eq!(after.location, rotation.transform(before.location));
+        }
+        assert_eq!(rotated.as_slice()[1].location, TouchPoint::new(312, 7));
+    }
+
+    #[test]
+    fn active_count_skips_inactive_phases() {
+        let mut frame = TouchFrame::<5>::new();
+        assert_eq!(frame.active_count(), 0);
+        for (id, phase) in [
+            Phase::Started,
+            Phase::Hovering(None),
+            Phase::Stationary,
+            Phase::Ended,
+            Phase::Moved,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut touch = touch(u8::try_from(id).unwrap());
+            touch.phase = phase;
+            frame.push(touch).unwrap();
+        }
+        assert_eq!(frame.len(), 5);
+        assert_eq!(frame.active_count(), 3);
+    }
+}
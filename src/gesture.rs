@@ -0,0 +1,130 @@
+//! Recognizers for common touch gestures
+//!
+//! Recognizers are fed one touch at a time and return a gesture once one is detected.
+//!
+//! Single-finger recognizers, such as [`TapRecognizer`] and [`SwipeRecognizer`], also
+//! take a timestamp in milliseconds, which may wrap around, and track up to `N`
+//! simultaneous touches by ID; additional touches are ignored. Two-finger recognizers,
+//! such as [`PinchRecognizer`], [`RotationRecognizer`], and [`ScrollRecognizer`], follow
+//! the first two touches to start and ignore any others. [`AngularVelocityTracker`]
+//! follows a pair in the same way, and takes a timestamp.
+
+mod angular_velocity;
+mod edge_swipe;
+mod flick;
+mod long_press;
+mod manager;
+mod pinch;
+mod rotation;
+mod scroll;
+mod swipe;
+mod tap;
+mod velocity;
+
+pub use angular_velocity::AngularVelocityTracker;
+pub use edge_swipe::{Edge, EdgeSwipe, EdgeSwipeConfig, EdgeSwipeRecognizer};
+pub use flick::{Flick, FlickConfig, FlickRecognizer};
+pub use long_press::{LongPress, LongPressConfig, LongPressRecognizer};
+pub use manager::{Gesture, GestureManager};
+pub use pinch::{Pinch, PinchRecognizer};
+pub use rotation::{Rotate, RotationRecognizer};
+pub use scroll::{Scroll, ScrollRecognizer};
+pub use swipe::{Swipe, SwipeConfig, SwipeRecognizer};
+pub use tap::{Tap, TapConfig, TapKind, TapRecognizer};
+pub use velocity::VelocityTracker;
+
+pub use crate::Direction;
+use crate::{Phase, Touch, TouchPoint};
+
+/// Returns `true` if the points are no more than `threshold` pixels apart
+fn within(a: TouchPoint, b: TouchPoint, threshold: u32) -> bool {
+    let threshold = i64::from(threshold);
+    a.distance_squared(&b) <= threshold * threshold
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Finger {
+    id: u8,
+    location: TouchPoint,
+}
+
+/// Tracks the first two touches to start, for two-finger gestures
+#[derive(Debug, Clone, Default)]
+struct FingerPair {
+    fingers: [Option<Finger>; 2],
+}
+
+/// How a touch event affected a [`FingerPair`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PairUpdate {
+    /// The touch is not one of the tracked fingers, or did not change the pair
+    Ignored,
+    /// A second finger touched down, so both fingers are now down
+    Completed,
+    /// One of the fingers moved while both were down
+    Moved,
+    /// One of the fingers lifted
+    Released,
+}
+
+impl FingerPair {
+    fn update(&mut self, touch: &Touch) -> PairUpdate {
+        match touch.phase {
+            Phase::Started => {
+                if self.finger_mut(touch.id).is_some() {
+                    return PairUpdate::Ignored;
+                }
+                let Some(slot) = self.fingers.iter_mut().find(|slot| slot.is_none()) else {
+                    return PairUpdate::Ignored;
+                };
+                *slot = Some(Finger {
+                    id: touch.id,
+                    location: touch.location,
+                });
+                if self.locations().is_some() {
+                    PairUpdate::Completed
+                } else {
+                    PairUpdate::Ignored
+                }
+            }
+            Phase::Moved | Phase::Stationary => {
+                let Some(finger) = self.finger_mut(touch.id) else {
+                    return PairUpdate::Ignored;
+                };
+                finger.location = touch.location;
+                if self.locations().is_some() {
+                    PairUpdate::Moved
+                } else {
+                    PairUpdate::Ignored
+                }
+            }
+            Phase::Ended | Phase::Cancelled => {
+                let Some(slot) = self
+                    .fingers
+                    .iter_mut()
+                    .find(|slot| slot.is_some_and(|finger| finger.id == touch.id))
+                else {
+                    return PairUpdate::Ignored;
+                };
+                *slot = None;
+                PairUpdate::Released
+            }
+            Phase::Hovering(_) => PairUpdate::Ignored,
+        }
+    }
+
+    /// Returns the locations of both fingers, if both are down
+    fn locations(&self) -> Option<(TouchPoint, TouchPoint)> {
+        match self.fingers {
+            [Some(a), Some(b)] => Some((a.location, b.location)),
+            _ => None,
+        }
+    }
+
+    fn finger_mut(&mut self, id: u8) -> Option<&mut Finger> {
+        self.fingers
+            .iter_mut()
+            .flatten()
+            .find(|finger| finger.id == id)
+    }
+}
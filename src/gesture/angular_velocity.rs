@@ -0,0 +1,213 @@
+use super::{FingerPair, PairUpdate};
+use crate::{
+    Touch, UnitAngle,
+    ring::{RingBuffer, Sample},
+    trig::atan2,
+};
+
+/// Number of recent samples kept for the pair
+const SAMPLES: usize = 8;
+
+/// Estimates how fast two fingers are rotating, e.g. for inertial spinning of a knob
+///
+/// The first two touches to start are tracked, as with
+/// [`RotationRecognizer`](super::RotationRecognizer), and the angle of the line from the
+/// first finger to the second is sampled each time either moves. Changes in angle are
+/// accumulated with [`UnitAngle::signed_difference`], so rotation across the 0/2π seam
+/// is continuous. Velocity is computed from the rotation over the most recent samples
+/// within a time window.
+///
+/// When either finger lifts, the velocity at that moment is kept until a new pair of
+/// fingers is down. A pair held still for the whole window before lifting has no
+/// velocity.
+#[derive(Debug, Clone)]
+pub struct AngularVelocityTracker {
+    window_ms: u32,
+    fingers: FingerPair,
+    previous_angle: Option<UnitAngle>,
+    /// Accumulated rotation, in the units of [`UnitAngle::signed_difference`]
+    samples: RingBuffer<Sample<i64>, SAMPLES>,
+    released: Option<f32>,
+}
+
+impl AngularVelocityTracker {
+    /// Create a tracker that estimates velocity over the last `window_ms` milliseconds
+    #[must_use]
+    pub fn new(window_ms: u32) -> Self {
+        Self {
+            window_ms,
+            fingers: FingerPair::default(),
+            previous_angle: None,
+            samples: RingBuffer::new(Sample {
+                value: 0,
+                timestamp_ms: 0,
+            }),
+            released: None,
+        }
+    }
+
+    /// Feed a touch event to the tracker
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) {
+        match self.fingers.update(touch) {
+            PairUpdate::Completed => {
+                self.previous_angle = self.angle();
+                self.released = None;
+                self.samples.clear();
+                self.samples.push(Sample {
+                    value: 0,
+                    timestamp_ms,
+                });
+            }
+            PairUpdate::Moved => {
+                let Some(angle) = self.angle() else {
+                    return;
+                };
+                let Some(previous) = self.previous_angle.replace(angle) else {
+                    return;
+                };
+                let total = self.total() + i64::from(previous.signed_difference(&angle));
+                self.samples.push(Sample {
+                    value: total,
+                    timestamp_ms,
+                });
+            }
+            PairUpdate::Released => {
+                if self.previous_angle.take().is_some() {
+                    // Sample the release, so a pair held still before lifting slows down
+                    self.samples.push(Sample {
+                        value: self.total(),
+                        timestamp_ms,
+                    });
+                    self.released = self.velocity();
+                    self.samples.clear();
+                }
+            }
+            PairUpdate::Ignored => {}
+        }
+    }
+
+    /// Returns the angular velocity in radians per second
+    ///
+    /// Positive velocity is from the +x axis toward the +y axis, which is clockwise on a
+    /// screen where y points down. While both fingers are down, this is the current
+    /// velocity. After either lifts, it is the velocity at release. Returns `None` if no
+    /// pair has been tracked, or there are too few samples.
+    #[must_use]
+    pub fn angular_velocity(&self) -> Option<f32> {
+        if self.previous_angle.is_some() {
+            self.velocity()
+        } else {
+            self.released
+        }
+    }
+
+    /// The newest accumulated rotation
+    fn total(&self) -> i64 {
+        self.samples.newest().map_or(0, |sample| sample.value)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn velocity(&self) -> Option<f32> {
+        let (newest, oldest, elapsed_ms) = self.samples.span(self.window_ms)?;
+        let radians = (newest - oldest) as f32 * core::f32::consts::PI / 32768.0;
+        Some(radians * 1000.0 / elapsed_ms as f32)
+    }
+
+    /// The angle of the line from the first finger to the second
+    fn angle(&self) -> Option<UnitAngle> {
+        let (a, b) = self.fingers.locations()?;
+        atan2(
+            i64::from(b.y) - i64::from(a.y),
+            i64::from(b.x) - i64::from(a.x),
+        )
+    }
+}
+
+impl Default for AngularVelocityTracker {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, TouchPoint, test_util::finger};
+
+    #[test]
+    fn steady_rotation_across_seam() {
+        let mut tracker = AngularVelocityTracker::default();
+        tracker.update(&finger(0, 0, 0, Phase::Started), 0);
+        // The second finger starts at 330° and turns 10° clockwise every 10 ms, through 0°
+        let point = |degrees: i32| {
+            let angle = UnitAngle::from_degrees(degrees.rem_euclid(360));
+            TouchPoint::new(1000, 0).rotate_around(TouchPoint::ORIGIN, &angle)
+        };
+        tracker.update(&finger(1, point(330).x, point(330).y, Phase::Started), 0);
+        assert_eq!(tracker.angular_velocity(), None);
+        for step in 1..=8 {
+            let location = point(330 + step * 10);
+            tracker.update(
+                &finger(1, location.x, location.y, Phase::Moved),
+                step.cast_unsigned() * 10,
+            );
+        }
+        // 10° per 10 ms is 1000°/s
+        let expected = 1000f32.to_radians();
+        let velocity = tracker.angular_velocity().unwrap();
+        assert!((velocity - expected).abs() < 0.05, "{velocity}");
+
+        tracker.update(&finger(0, 0, 0, Phase::Ended), 80);
+        let released = tracker.angular_velocity().unwrap();
+        assert!((released - expected).abs() < 0.05, "{released}");
+    }
+
+    /// Turn the second finger 10° every 10 ms, from 0 ms to 80 ms
+    fn spin(tracker: &mut AngularVelocityTracker) {
+        tracker.update(&finger(0, 0, 0, Phase::Started), 0);
+        tracker.update(&finger(1, 1000, 0, Phase::Started), 0);
+        for step in 1..=8u32 {
+            let angle = UnitAngle::from_degrees(step * 10);
+            let location = TouchPoint::new(1000, 0).rotate_around(TouchPoint::ORIGIN, &angle);
+            tracker.update(&finger(1, location.x, location.y, Phase::Moved), step * 10);
+        }
+    }
+
+    #[test]
+    fn delayed_release_slows_down() {
+        let mut tracker = AngularVelocityTracker::default();
+        spin(&mut tracker);
+        // Only 50° of the last 100 ms was spent turning
+        tracker.update(&finger(0, 0, 0, Phase::Ended), 130);
+        let released = tracker.angular_velocity().unwrap();
+        assert!((released - 500f32.to_radians()).abs() < 0.05, "{released}");
+
+        let mut tracker = AngularVelocityTracker::default();
+        spin(&mut tracker);
+        tracker.update(&finger(0, 0, 0, Phase::Ended), 500);
+        assert_eq!(tracker.angular_velocity(), None);
+    }
+
+    #[test]
+    fn fingers_at_opposite_extremes() {
+        let mut tracker = AngularVelocityTracker::default();
+        tracker.update(&finger(0, i32::MIN, i32::MIN, Phase::Started), 0);
+        tracker.update(&finger(1, i32::MAX, i32::MIN, Phase::Started), 0);
+        tracker.update(&finger(1, i32::MAX, i32::MAX, Phase::Moved), 50);
+        // 45° in 50 ms is 900°/s
+        let velocity = tracker.angular_velocity().unwrap();
+        assert!((velocity - 900f32.to_radians()).abs() < 0.05, "{velocity}");
+    }
+
+    #[test]
+    fn stationary_pair() {
+        let mut tracker = AngularVelocityTracker::default();
+        tracker.update(&finger(0, 100, 100, Phase::Started), 0);
+        tracker.update(&finger(1, 200, 150, Phase::Started), 0);
+        for timestamp in [16, 33, 50, 66] {
+            tracker.update(&finger(1, 200, 150, Phase::Stationary), timestamp);
+        }
+        let velocity = tracker.angular_velocity().unwrap();
+        assert!(velocity.abs() < 0.001, "{velocity}");
+    }
+}
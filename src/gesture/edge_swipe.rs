@@ -0,0 +1,199 @@
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// An edge of the panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Edge {
+    /// The edge at x = 0
+    Left,
+    /// The edge at the largest x
+    Right,
+    /// The edge at y = 0
+    Top,
+    /// The edge at the largest y
+    Bottom,
+}
+
+/// A detected swipe in from an edge of the panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EdgeSwipe {
+    /// The edge the touch started from
+    pub edge: Edge,
+    /// Distance travelled inward from the start, perpendicular to the edge, in pixels
+    pub distance: u32,
+}
+
+/// Thresholds for recognizing edge swipes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EdgeSwipeConfig {
+    /// Width, in pixels, of the band along each edge where an edge swipe can start
+    pub margin: u32,
+    /// Minimum distance, in pixels, a touch must travel inward from where it started
+    pub min_distance: u32,
+}
+
+impl Default for EdgeSwipeConfig {
+    fn default() -> Self {
+        Self {
+            margin: 20,
+            min_distance: 40,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Start {
+    edge: Edge,
+    location: TouchPoint,
+}
+
+/// Recognizes swipes that start at an edge of the panel and move inward
+///
+/// A touch that starts within [`EdgeSwipeConfig::margin`] of an edge is reported once,
+/// as soon as it has moved at least [`EdgeSwipeConfig::min_distance`] inward, so a
+/// drawer can follow the finger without waiting for it to lift. Movement along the edge
+/// does not count. Touches that start in a corner belong to the nearest edge, and
+/// touches that start in the interior are ignored.
+#[derive(Debug, Clone)]
+pub struct EdgeSwipeRecognizer<const N: usize> {
+    width: u32,
+    height: u32,
+    config: EdgeSwipeConfig,
+    starts: IdMap<Start, N>,
+}
+
+impl<const N: usize> EdgeSwipeRecognizer<N> {
+    /// Create a recognizer for a `width` × `height` pixel panel
+    #[must_use]
+    pub fn new(width: u32, height: u32, config: EdgeSwipeConfig) -> Self {
+        Self {
+            width,
+            height,
+            config,
+            starts: IdMap::new(),
+        }
+    }
+
+    /// Feed a touch event to the recognizer, returning an edge swipe if one was detected
+    pub fn update(&mut self, touch: &Touch) -> Option<EdgeSwipe> {
+        match touch.phase {
+            Phase::Started => {
+                self.starts.remove(touch.id);
+                if let Some(edge) = self.edge_at(touch.location) {
+                    let start = Start {
+                        edge,
+                        location: touch.location,
+                    };
+                    _ = self.starts.insert(touch.id, start);
+                }
+                None
+            }
+            Phase::Moved | Phase::Ended => {
+                let start = *self.starts.get(touch.id)?;
+                if touch.phase == Phase::Ended {
+                    self.starts.remove(touch.id);
+                }
+                let dx = i64::from(touch.location.x) - i64::from(start.location.x);
+                let dy = i64::from(touch.location.y) - i64::from(start.location.y);
+                let inward = match start.edge {
+                    Edge::Left => dx,
+                    Edge::Right => -dx,
+                    Edge::Top => dy,
+                    Edge::Bottom => -dy,
+                };
+                let distance = u32::try_from(inward).ok()?;
+                if distance < self.config.min_distance {
+                    return None;
+                }
+                self.starts.remove(touch.id);
+                Some(EdgeSwipe {
+                    edge: start.edge,
+                    distance,
+                })
+            }
+            Phase::Cancelled => {
+                self.starts.remove(touch.id);
+                None
+            }
+            Phase::Stationary | Phase::Hovering(_) => None,
+        }
+    }
+
+    /// Returns the edge nearest to `point`, if it is within the margin
+    fn edge_at(&self, point: TouchPoint) -> Option<Edge> {
+        let far = |size: u32| i64::from(size) - 1;
+        [
+            (Edge::Left, i64::from(point.x)),
+            (Edge::Right, far(self.width) - i64::from(point.x)),
+            (Edge::Top, i64::from(point.y)),
+            (Edge::Bottom, far(self.height) - i64::from(point.y)),
+        ]
+        .into_iter()
+        .filter(|&(_, gap)| gap < i64::from(self.config.margin))
+        .min_by_key(|&(_, gap)| gap)
+        .map(|(edge, _)| edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    fn recognizer() -> EdgeSwipeRecognizer<2> {
+        EdgeSwipeRecognizer::new(800, 480, EdgeSwipeConfig::default())
+    }
+
+    #[test]
+    fn left_edge_swipe() {
+        let mut recognizer = recognizer();
+        assert_eq!(recognizer.update(&finger(0, 5, 200, Phase::Started)), None);
+        assert_eq!(recognizer.update(&finger(0, 30, 260, Phase::Moved)), None);
+        assert_eq!(
+            recognizer.update(&finger(0, 50, 300, Phase::Moved)),
+            Some(EdgeSwipe {
+                edge: Edge::Left,
+                distance: 45,
+            })
+        );
+        // Reported only once per touch
+        assert_eq!(recognizer.update(&finger(0, 120, 300, Phase::Moved)), None);
+        assert_eq!(recognizer.update(&finger(0, 120, 300, Phase::Ended)), None);
+
+        recognizer.update(&finger(0, 470, 475, Phase::Started));
+        let swipe = recognizer
+            .update(&finger(0, 470, 400, Phase::Ended))
+            .unwrap();
+        assert_eq!(swipe.edge, Edge::Bottom);
+    }
+
+    #[test]
+    fn swipe_to_extreme_coordinates() {
+        let mut recognizer = recognizer();
+        recognizer.update(&finger(0, 795, 200, Phase::Started));
+        assert_eq!(
+            recognizer.update(&finger(0, i32::MIN, 200, Phase::Moved)),
+            Some(EdgeSwipe {
+                edge: Edge::Right,
+                distance: 795 + 2_147_483_648,
+            })
+        );
+    }
+
+    #[test]
+    fn interior_swipe_is_ignored() {
+        let mut recognizer = recognizer();
+        recognizer.update(&finger(0, 100, 200, Phase::Started));
+        assert_eq!(recognizer.update(&finger(0, 400, 200, Phase::Moved)), None);
+        assert_eq!(recognizer.update(&finger(0, 700, 200, Phase::Ended)), None);
+
+        // Moving outward from an edge is not an edge swipe either
+        recognizer.update(&finger(0, 790, 200, Phase::Started));
+        assert_eq!(recognizer.update(&finger(0, 799, 100, Phase::Ended)), None);
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Direction, VelocityTracker};
+use crate::{Phase, Touch, TouchPoint};
+
+/// A detected flick
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Flick {
+    /// Direction of the dominant axis of the release velocity
+    pub direction: Direction,
+    /// Speed at the moment the touch ended, in pixels per second
+    pub velocity: f32,
+}
+
+/// Thresholds for recognizing flicks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FlickConfig {
+    /// Minimum release speed, in pixels per second
+    pub min_velocity: u32,
+    /// Duration, in milliseconds, of movement before release used to estimate velocity
+    pub window_ms: u32,
+}
+
+impl Default for FlickConfig {
+    fn default() -> Self {
+        Self {
+            min_velocity: 500,
+            window_ms: 100,
+        }
+    }
+}
+
+/// Recognizes flicks: touches that are moving quickly when they are released
+///
+/// Unlike a [`Swipe`](super::Swipe), a flick depends only on the velocity over the last
+/// [`FlickConfig::window_ms`] before the touch ends, not on the total distance or
+/// duration. A long drag that slows down before release is not a flick, while a short,
+/// quick movement is.
+#[derive(Debug, Clone)]
+pub struct FlickRecognizer<const N: usize> {
+    min_velocity: u32,
+    tracker: VelocityTracker<N>,
+}
+
+impl<const N: usize> FlickRecognizer<N> {
+    /// Create a new flick recognizer
+    #[must_use]
+    pub fn new(config: FlickConfig) -> Self {
+        Self {
+            min_velocity: config.min_velocity,
+            tracker: VelocityTracker::new(config.window_ms),
+        }
+    }
+
+    /// Feed a touch event to the recognizer, returning a flick if one was completed
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) -> Option<Flick> {
+        self.tracker.update(touch, timestamp_ms);
+        if touch.phase != Phase::Ended {
+            return None;
+        }
+        let (vx, vy) = self.tracker.flick(touch.id)?;
+        let speed = fixed::types::U80F48::saturating_from_num(vx * vx + vy * vy)
+            .sqrt()
+            .to_num::<f32>();
+        #[expect(clippy::cast_precision_loss)]
+        let min_velocity = self.min_velocity as f32;
+        if speed < min_velocity {
+            return None;
+        }
+        // Whole pixels per second are plenty to pick the dominant axis
+        #[expect(clippy::cast_possible_truncation)]
+        let direction = TouchPoint::new(vx as i32, vy as i32).dominant_direction();
+        Some(Flick {
+            direction,
+            velocity: speed,
+        })
+    }
+}
+
+impl<const N: usize> Default for FlickRecognizer<N> {
+    fn default() -> Self {
+        Self::new(FlickConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn fast_release_is_a_flick() {
+        let mut recognizer = FlickRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 100, Phase::Started), 0);
+        recognizer.update(&finger(0, 100, 80, Phase::Moved), 16);
+        recognizer.update(&finger(0, 100, 60, Phase::Moved), 32);
+        let flick = recognizer
+            .update(&finger(0, 100, 40, Phase::Ended), 48)
+            .unwrap();
+        assert_eq!(flick.direction, Direction::Up);
+        assert!((flick.velocity - 1250.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn flicks_with_rotating_ids() {
+        let mut recognizer = FlickRecognizer::<2>::default();
+        for id in 0..5u8 {
+            let start = u32::from(id) * 100;
+            recognizer.update(&finger(id, 100, 100, Phase::Started), start);
+            recognizer.update(&finger(id, 120, 100, Phase::Moved), start + 16);
+            let flick = recognizer.update(&finger(id, 140, 100, Phase::Ended), start + 32);
+            assert_eq!(flick.map(|flick| flick.direction), Some(Direction::Right));
+        }
+    }
+
+    #[test]
+    fn slow_release_after_long_drag_is_not_a_flick() {
+        let mut recognizer = FlickRecognizer::<2>::default();
+        recognizer.update(&finger(0, 0, 100, Phase::Started), 0);
+        for step in 1..=10 {
+            recognizer.update(
+                &finger(0, step * 50, 100, Phase::Moved),
+                step.cast_unsigned() * 20,
+            );
+        }
+        for step in 1..=10 {
+            recognizer.update(
+                &finger(0, 500 + step, 100, Phase::Moved),
+                200 + step.cast_unsigned() * 20,
+            );
+        }
+        assert_eq!(
+            recognizer.update(&finger(0, 511, 100, Phase::Ended), 420),
+            None
+        );
+    }
+}
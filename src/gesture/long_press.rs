@@ -0,0 +1,215 @@
+use super::within;
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// A detected long press
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LongPress {
+    /// Location where the press started
+    pub location: TouchPoint,
+}
+
+/// Thresholds for recognizing long presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LongPressConfig {
+    /// Maximum distance, in pixels, a touch may move before the press is cancelled
+    pub movement_threshold: u32,
+    /// Minimum duration, in milliseconds, a touch must be held
+    pub duration_ms: u32,
+}
+
+impl Default for LongPressConfig {
+    fn default() -> Self {
+        Self {
+            movement_threshold: 10,
+            duration_ms: 500,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    location: TouchPoint,
+    timestamp_ms: u32,
+    fired: bool,
+}
+
+/// Recognizes long presses
+///
+/// A long press is reported once per touch, on the first update at least
+/// [`LongPressConfig::duration_ms`] after the touch started. Moving further than
+/// [`LongPressConfig::movement_threshold`] before then cancels the press.
+///
+/// Many controllers stop reporting a touch that does not move, so a finger held still
+/// may produce no updates at all. Call [`poll`](Self::poll) periodically to detect
+/// those presses from the clock alone.
+#[derive(Debug, Clone)]
+pub struct LongPressRecognizer<const N: usize> {
+    config: LongPressConfig,
+    presses: IdMap<Press, N>,
+}
+
+impl<const N: usize> LongPressRecognizer<N> {
+    /// Create a new long press recognizer
+    #[must_use]
+    pub fn new(config: LongPressConfig) -> Self {
+        Self {
+            config,
+            presses: IdMap::new(),
+        }
+    }
+
+    /// Feed a touch event to the recognizer, returning a long press if one was detected
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) -> Option<LongPress> {
+        match touch.phase {
+            Phase::Started => {
+                let press = Press {
+                    location: touch.location,
+                    timestamp_ms,
+                    fired: false,
+                };
+                _ = self.presses.insert(touch.id, press);
+                None
+            }
+            Phase::Moved | Phase::Stationary => {
+                let press = self.presses.get_mut(touch.id)?;
+                if press.fired {
+                    return None;
+                }
+                if !within(
+                    press.location,
+                    touch.location,
+                    self.config.movement_threshold,
+                ) {
+                    self.presses.remove(touch.id);
+                    return None;
+                }
+                if timestamp_ms.wrapping_sub(press.timestamp_ms) < self.config.duration_ms {
+                    return None;
+                }
+                press.fired = true;
+                Some(LongPress {
+                    location: press.location,
+                })
+            }
+            Phase::Ended | Phase::Cancelled => {
+                self.presses.remove(touch.id);
+                None
+            }
+            Phase::Hovering(_) => None,
+        }
+    }
+
+    /// Check held touches against the clock, returning a long press if one is now due
+    ///
+    /// This detects presses on touches that have not been updated since they started. If
+    /// several presses are due at once, one is returned per call.
+    pub fn poll(&mut self, timestamp_ms: u32) -> Option<LongPress> {
+        self.poll_touch(timestamp_ms).map(|(_, press)| press)
+    }
+
+    /// As [`poll`](Self::poll), also returning the ID of the touch
+    pub(crate) fn poll_touch(&mut self, timestamp_ms: u32) -> Option<(u8, LongPress)> {
+        let duration_ms = self.config.duration_ms;
+        let (id, press) = self.presses.iter_mut().find(|(_, press)| {
+            !press.fired && timestamp_ms.wrapping_sub(press.timestamp_ms) >= duration_ms
+        })?;
+        press.fired = true;
+        Some((
+            id,
+            LongPress {
+                location: press.location,
+            },
+        ))
+    }
+}
+
+impl<const N: usize> Default for LongPressRecognizer<N> {
+    fn default() -> Self {
+        Self::new(LongPressConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn held_press_fires_once() {
+        let mut recognizer = LongPressRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 100, Phase::Started), 0);
+        assert_eq!(
+            recognizer.update(&finger(0, 102, 100, Phase::Moved), 250),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 103, 100, Phase::Moved), 500),
+            Some(LongPress {
+                location: TouchPoint::new(100, 100)
+            })
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 103, 100, Phase::Moved), 750),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 103, 100, Phase::Ended), 800),
+            None
+        );
+    }
+
+    #[test]
+    fn poll_detects_still_finger() {
+        let mut recognizer = LongPressRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 100, Phase::Started), 0);
+        assert_eq!(recognizer.poll(499), None);
+        assert_eq!(
+            recognizer.poll(500),
+            Some(LongPress {
+                location: TouchPoint::new(100, 100)
+            })
+        );
+        assert_eq!(recognizer.poll(600), None);
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Moved), 700),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Ended), 800),
+            None
+        );
+        assert_eq!(recognizer.poll(1000), None);
+    }
+
+    #[test]
+    fn early_release_does_not_fire() {
+        let mut recognizer = LongPressRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 100, Phase::Started), 0);
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Ended), 300),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Moved), 600),
+            None
+        );
+    }
+
+    #[test]
+    fn drifting_out_of_radius_cancels() {
+        let mut recognizer = LongPressRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 100, Phase::Started), 0);
+        assert_eq!(
+            recognizer.update(&finger(0, 120, 100, Phase::Moved), 200),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Moved), 600),
+            None
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use super::{
+    LongPress, LongPressConfig, LongPressRecognizer, Swipe, SwipeConfig, SwipeRecognizer, Tap,
+    TapConfig, TapRecognizer,
+};
+use crate::{Phase, Touch};
+
+/// A gesture detected by a [`GestureManager`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Gesture {
+    /// A single or double tap
+    Tap(Tap),
+    /// A directional swipe
+    Swipe(Swipe),
+    /// A long press
+    LongPress(LongPress),
+}
+
+/// Feeds each touch to tap, swipe, and long press recognizers, with mutual exclusion
+///
+/// Recognizers are consulted in priority order: long press, then swipe, then tap. The
+/// first to detect a gesture claims the touch, and the remaining recognizers are sent a
+/// [`Phase::Cancelled`] copy of it instead, so they forget the touch. This way a long
+/// press suppresses the tap that would otherwise be reported when the finger lifts, and
+/// at most one gesture is reported per touch event.
+#[derive(Debug, Clone)]
+pub struct GestureManager<const N: usize> {
+    long_press: LongPressRecognizer<N>,
+    swipe: SwipeRecognizer<N>,
+    tap: TapRecognizer<N>,
+}
+
+impl<const N: usize> GestureManager<N> {
+    /// Create a manager with the given thresholds for each recognizer
+    #[must_use]
+    pub fn new(tap: TapConfig, swipe: SwipeConfig, long_press: LongPressConfig) -> Self {
+        Self {
+            long_press: LongPressRecognizer::new(long_press),
+            swipe: SwipeRecognizer::new(swipe),
+            tap: TapRecognizer::new(tap),
+        }
+    }
+
+    /// Feed a touch event to every recognizer, returning a gesture if one was detected
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) -> Option<Gesture> {
+        let cancelled = Touch {
+            phase: Phase::Cancelled,
+            ..*touch
+        };
+
+        if let Some(press) = self.long_press.update(touch, timestamp_ms) {
+            self.swipe.update(&cancelled, timestamp_ms);
+            self.tap.update(&cancelled, timestamp_ms);
+            return Some(Gesture::LongPress(press));
+        }
+        if let Some(swipe) = self.swipe.update(touch, timestamp_ms) {
+            self.tap.update(&cancelled, timestamp_ms);
+            return Some(Gesture::Swipe(swipe));
+        }
+        self.tap.update(touch, timestamp_ms).map(Gesture::Tap)
+    }
+
+    /// Check held touches against the clock, returning a long press if one is now due
+    ///
+    /// A finger held still often produces no further updates, so call this periodically
+    /// as for [`LongPressRecognizer::poll`]. A long press found this way claims its touch
+    /// in the same way as one found by [`update`](Self::update).
+    pub fn poll(&mut self, timestamp_ms: u32) -> Option<Gesture> {
+        let (id, press) = self.long_press.poll_touch(timestamp_ms)?;
+        let cancelled = Touch::finger(id, press.location, Phase::Cancelled);
+        self.swipe.update(&cancelled, timestamp_ms);
+        self.tap.update(&cancelled, timestamp_ms);
+        Some(Gesture::LongPress(press))
+    }
+}
+
+impl<const N: usize> Default for GestureManager<N> {
+    fn default() -> Self {
+        Self::new(
+            TapConfig::default(),
+            SwipeConfig::default(),
+            LongPressConfig::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TouchPoint, gesture::TapKind, test_util::finger};
+
+    /// Taps that may last long enough to overlap a long press
+    fn tap_config() -> TapConfig {
+        TapConfig {
+            max_duration_ms: 1000,
+            ..TapConfig::default()
+        }
+    }
+
+    fn manager() -> GestureManager<2> {
+        GestureManager::new(
+            tap_config(),
+            SwipeConfig::default(),
+            LongPressConfig::default(),
+        )
+    }
+
+    #[test]
+    fn long_press_suppresses_tap() {
+        // On its own, the tap recognizer reports this touch when it lifts
+        let mut tap = TapRecognizer::<2>::new(tap_config());
+        tap.update(&finger(0, 100, 100, Phase::Started), 0);
+        tap.update(&finger(0, 101, 100, Phase::Moved), 600);
+        assert!(
+            tap.update(&finger(0, 101, 100, Phase::Ended), 700)
+                .is_some()
+        );
+
+        let mut manager = manager();
+        assert_eq!(
+            manager.update(&finger(0, 100, 100, Phase::Started), 0),
+            None
+        );
+        assert_eq!(
+            manager.update(&finger(0, 101, 100, Phase::Moved), 600),
+            Some(Gesture::LongPress(LongPress {
+                location: TouchPoint::new(100, 100)
+            }))
+        );
+        assert_eq!(
+            manager.update(&finger(0, 101, 100, Phase::Ended), 700),
+            None
+        );
+    }
+
+    #[test]
+    fn polled_long_press_suppresses_tap() {
+        let mut manager = manager();
+        assert_eq!(
+            manager.update(&finger(0, 100, 100, Phase::Started), 0),
+            None
+        );
+        assert_eq!(manager.poll(400), None);
+        assert_eq!(
+            manager.poll(500),
+            Some(Gesture::LongPress(LongPress {
+                location: TouchPoint::new(100, 100)
+            }))
+        );
+        assert_eq!(manager.poll(600), None);
+        assert_eq!(
+            manager.update(&finger(0, 100, 100, Phase::Ended), 700),
+            None
+        );
+    }
+
+    #[test]
+    fn unclaimed_touches_reach_each_recognizer() {
+        let mut manager = manager();
+        manager.update(&finger(0, 100, 100, Phase::Started), 0);
+        assert_eq!(
+            manager.update(&finger(0, 100, 100, Phase::Ended), 100),
+            Some(Gesture::Tap(Tap {
+                location: TouchPoint::new(100, 100),
+                kind: TapKind::Single,
+            }))
+        );
+
+        manager.update(&finger(0, 100, 100, Phase::Started), 1000);
+        let swipe = manager.update(&finger(0, 300, 100, Phase::Ended), 1100);
+        assert!(matches!(swipe, Some(Gesture::Swipe(_))));
+    }
+}
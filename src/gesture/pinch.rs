@@ -0,0 +1,131 @@
+use fixed::types::U96F32;
+
+use super::{FingerPair, PairUpdate};
+use crate::{Touch, TouchPoint};
+
+/// A detected pinch
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Pinch {
+    /// Ratio of the current distance between the fingers to their initial distance
+    ///
+    /// Values greater than 1 indicate the fingers are moving apart (zooming in).
+    pub scale: f32,
+    /// Midpoint between the two fingers
+    pub center: TouchPoint,
+}
+
+/// Recognizes two-finger pinches
+///
+/// The first two touches to start are tracked, and any additional touches are ignored.
+/// A pinch is reported each time either finger moves. Lifting either finger resets the
+/// initial distance, which is captured again once a second finger is down.
+#[derive(Debug, Clone, Default)]
+pub struct PinchRecognizer {
+    fingers: FingerPair,
+    initial_distance_squared: Option<i64>,
+}
+
+impl PinchRecognizer {
+    /// Create a new pinch recognizer
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a touch event to the recognizer, returning a pinch if both fingers are down
+    pub fn update(&mut self, touch: &Touch) -> Option<Pinch> {
+        match self.fingers.update(touch) {
+            PairUpdate::Completed => {
+                self.capture_initial_distance();
+                None
+            }
+            PairUpdate::Moved => {
+                self.capture_initial_distance();
+                let (a, b) = self.fingers.locations()?;
+                let initial = self.initial_distance_squared?;
+                let current = a.distance_squared(&b);
+                Some(Pinch {
+                    scale: sqrt_ratio(current, initial),
+                    center: a.midpoint(&b),
+                })
+            }
+            PairUpdate::Released => {
+                self.initial_distance_squared = None;
+                None
+            }
+            PairUpdate::Ignored => None,
+        }
+    }
+
+    /// Captures the initial distance once both fingers are down and apart
+    fn capture_initial_distance(&mut self) {
+        if self.initial_distance_squared.is_none()
+            && let Some((a, b)) = self.fingers.locations()
+        {
+            let distance_squared = a.distance_squared(&b);
+            self.initial_distance_squared = (distance_squared > 0).then_some(distance_squared);
+        }
+    }
+}
+
+/// Returns `sqrt(numerator / denominator)` for positive squared distances
+fn sqrt_ratio(numerator: i64, denominator: i64) -> f32 {
+    let numerator = u128::from(numerator.unsigned_abs()) << U96F32::FRAC_NBITS;
+    let denominator = u128::from(denominator.unsigned_abs());
+    U96F32::from_bits(numerator / denominator)
+        .sqrt()
+        .to_num::<f32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, test_util::finger};
+
+    #[test]
+    fn fingers_moving_apart() {
+        let mut recognizer = PinchRecognizer::new();
+        assert_eq!(
+            recognizer.update(&finger(0, 100, 100, Phase::Started)),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(1, 200, 100, Phase::Started)),
+            None
+        );
+        let pinch = recognizer
+            .update(&finger(1, 300, 100, Phase::Moved))
+            .unwrap();
+        assert!((pinch.scale - 2.0).abs() < 0.001);
+        assert_eq!(pinch.center, TouchPoint::new(200, 100));
+    }
+
+    #[test]
+    fn fingers_moving_together() {
+        let mut recognizer = PinchRecognizer::new();
+        recognizer.update(&finger(3, 0, 0, Phase::Started));
+        recognizer.update(&finger(5, 0, 200, Phase::Started));
+        recognizer.update(&finger(3, 0, 50, Phase::Moved));
+        let pinch = recognizer.update(&finger(5, 0, 150, Phase::Moved)).unwrap();
+        assert!((pinch.scale - 0.5).abs() < 0.001);
+        assert_eq!(pinch.center, TouchPoint::new(0, 100));
+    }
+
+    #[test]
+    fn resets_when_a_finger_lifts_and_ignores_extra_fingers() {
+        let mut recognizer = PinchRecognizer::new();
+        recognizer.update(&finger(0, 0, 0, Phase::Started));
+        recognizer.update(&finger(1, 100, 0, Phase::Started));
+        recognizer.update(&finger(2, 500, 500, Phase::Started));
+        assert_eq!(recognizer.update(&finger(2, 600, 600, Phase::Moved)), None);
+
+        recognizer.update(&finger(1, 100, 0, Phase::Ended));
+        assert_eq!(recognizer.update(&finger(0, 10, 0, Phase::Moved)), None);
+
+        recognizer.update(&finger(4, 110, 0, Phase::Started));
+        let pinch = recognizer.update(&finger(4, 210, 0, Phase::Moved)).unwrap();
+        assert!((pinch.scale - 2.0).abs() < 0.001);
+    }
+}
@@ -0,0 +1,140 @@
+use super::{FingerPair, PairUpdate};
+use crate::{Touch, TouchPoint, UnitAngle, trig::atan2};
+
+/// A detected two-finger rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rotate {
+    /// Rotation since the previous report
+    ///
+    /// Positive rotation is from the +x axis toward the +y axis, which is clockwise on a
+    /// screen where y points down.
+    pub angle_delta: UnitAngle,
+    /// Accumulated rotation since the second finger touched down
+    ///
+    /// This is in the units of [`UnitAngle::signed_difference`] (2⁻¹⁵ π radians), and
+    /// continues past a full turn, so 65536 is one full clockwise turn.
+    pub total: i32,
+    /// Midpoint between the two fingers
+    pub center: TouchPoint,
+}
+
+/// Recognizes two-finger rotations
+///
+/// The first two touches to start are tracked, and any additional touches are ignored.
+/// A rotation is reported each time either finger moves, based on the change in angle
+/// of the line from the first finger to the second. Lifting either finger resets the
+/// accumulated rotation.
+#[derive(Debug, Clone, Default)]
+pub struct RotationRecognizer {
+    fingers: FingerPair,
+    previous_angle: Option<UnitAngle>,
+    total: i32,
+}
+
+impl RotationRecognizer {
+    /// Create a new rotation recognizer
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a touch event to the recognizer, returning a rotation if both fingers are down
+    pub fn update(&mut self, touch: &Touch) -> Option<Rotate> {
+        match self.fingers.update(touch) {
+            PairUpdate::Completed => {
+                self.previous_angle = self.angle();
+                self.total = 0;
+                None
+            }
+            PairUpdate::Moved => {
+                let (a, b) = self.fingers.locations()?;
+                let angle = self.angle()?;
+                let previous = self.previous_angle.replace(angle)?;
+                self.total = self.total.wrapping_add(previous.signed_difference(&angle));
+                Some(Rotate {
+                    angle_delta: angle - previous,
+                    total: self.total,
+                    center: a.midpoint(&b),
+                })
+            }
+            PairUpdate::Released => {
+                self.previous_angle = None;
+                self.total = 0;
+                None
+            }
+            PairUpdate::Ignored => None,
+        }
+    }
+
+    /// The angle of the line from the first finger to the second
+    fn angle(&self) -> Option<UnitAngle> {
+        let (a, b) = self.fingers.locations()?;
+        atan2(
+            i64::from(b.y) - i64::from(a.y),
+            i64::from(b.x) - i64::from(a.x),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, test_util::finger};
+
+    fn degrees(raw: i32) -> f32 {
+        #[expect(clippy::cast_precision_loss)]
+        let raw = raw as f32;
+        raw * 180.0 / 32768.0
+    }
+
+    #[test]
+    fn rotate_90_degrees() {
+        let mut recognizer = RotationRecognizer::new();
+        recognizer.update(&finger(0, 100, 100, Phase::Started));
+        recognizer.update(&finger(1, 200, 100, Phase::Started));
+        let rotate = recognizer
+            .update(&finger(1, 100, 200, Phase::Moved))
+            .unwrap();
+        assert!((rotate.angle_delta.as_degrees_f32() - 90.0).abs() < 0.1);
+        assert!((degrees(rotate.total) - 90.0).abs() < 0.1);
+        assert_eq!(rotate.center, TouchPoint::new(100, 150));
+    }
+
+    #[test]
+    fn fingers_at_opposite_extremes() {
+        let mut recognizer = RotationRecognizer::new();
+        recognizer.update(&finger(0, i32::MIN, i32::MIN, Phase::Started));
+        recognizer.update(&finger(1, i32::MAX, i32::MIN, Phase::Started));
+        let rotate = recognizer
+            .update(&finger(1, i32::MAX, i32::MAX, Phase::Moved))
+            .unwrap();
+        assert!((rotate.angle_delta.as_degrees_f32() - 45.0).abs() < 0.1);
+        assert_eq!(rotate.center, TouchPoint::ORIGIN);
+    }
+
+    #[test]
+    fn rotate_across_seam_and_past_full_turn() {
+        let mut recognizer = RotationRecognizer::new();
+        recognizer.update(&finger(0, 0, 0, Phase::Started));
+        // 350°, just above the +x axis on screen
+        recognizer.update(&finger(1, 985, -174, Phase::Started));
+        // 10°, just below the +x axis on screen
+        let rotate = recognizer
+            .update(&finger(1, 985, 174, Phase::Moved))
+            .unwrap();
+        assert!((rotate.angle_delta.as_degrees_f32() - 20.0).abs() < 0.1);
+        assert!((degrees(rotate.total) - 20.0).abs() < 0.1);
+
+        // Keep spinning counterclockwise on screen through -340° in total
+        for (x, y) in [(0, -1000), (-1000, 0), (0, 1000), (1000, 0), (985, -174)] {
+            recognizer.update(&finger(1, x, y, Phase::Moved));
+        }
+        let rotate = recognizer
+            .update(&finger(1, 0, -1000, Phase::Moved))
+            .unwrap();
+        assert!((rotate.angle_delta.as_degrees_f32() - 280.0).abs() < 0.1);
+        assert!((degrees(rotate.total) + 440.0).abs() < 0.1);
+    }
+}
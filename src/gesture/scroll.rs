@@ -0,0 +1,112 @@
+use super::{FingerPair, PairUpdate};
+use crate::{Touch, TouchPoint};
+
+/// A detected two-finger scroll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Scroll {
+    /// Average movement of both fingers since the previous report
+    pub delta: TouchPoint,
+}
+
+/// Recognizes trackpad-style two-finger scrolls
+///
+/// The first two touches to start are tracked, and any additional touches are ignored.
+/// A scroll is reported once both fingers have moved since the previous report, which
+/// for a device reporting every touch each frame is once per frame. Movement is only
+/// treated as a scroll if the fingers move coherently, in directions less than 90°
+/// apart; movement in opposing directions, as in a pinch or rotation, is discarded.
+/// Lifting either finger ends the scroll.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollRecognizer {
+    fingers: FingerPair,
+    anchors: Option<(TouchPoint, TouchPoint)>,
+}
+
+impl ScrollRecognizer {
+    /// Create a new scroll recognizer
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a touch event to the recognizer, returning a scroll if both fingers moved
+    /// together
+    pub fn update(&mut self, touch: &Touch) -> Option<Scroll> {
+        match self.fingers.update(touch) {
+            PairUpdate::Completed => {
+                self.anchors = self.fingers.locations();
+                None
+            }
+            PairUpdate::Moved => {
+                let (a, b) = self.fingers.locations()?;
+                let (anchor_a, anchor_b) = self.anchors?;
+                let (delta_a, delta_b) = (a.saturating_sub(anchor_a), b.saturating_sub(anchor_b));
+                if delta_a.is_origin() || delta_b.is_origin() {
+                    return None;
+                }
+                self.anchors = Some((a, b));
+                (delta_a.dot(&delta_b) > 0).then(|| Scroll {
+                    delta: delta_a.midpoint(&delta_b),
+                })
+            }
+            PairUpdate::Released => {
+                self.anchors = None;
+                None
+            }
+            PairUpdate::Ignored => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, test_util::finger};
+
+    #[test]
+    fn parallel_movement_scrolls() {
+        let mut recognizer = ScrollRecognizer::new();
+        recognizer.update(&finger(0, 100, 100, Phase::Started));
+        recognizer.update(&finger(1, 200, 100, Phase::Started));
+        for frame in 1..=3 {
+            let y = 100 - frame * 10;
+            assert_eq!(recognizer.update(&finger(0, 100, y, Phase::Moved)), None);
+            assert_eq!(
+                recognizer.update(&finger(1, 200 + frame * 2, y, Phase::Moved)),
+                Some(Scroll {
+                    delta: TouchPoint::new(1, -10),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn scroll_across_extreme_coordinates() {
+        let mut recognizer = ScrollRecognizer::new();
+        recognizer.update(&finger(0, i32::MIN, 0, Phase::Started));
+        recognizer.update(&finger(1, i32::MIN, 10, Phase::Started));
+        recognizer.update(&finger(0, i32::MAX, 0, Phase::Moved));
+        assert_eq!(
+            recognizer.update(&finger(1, i32::MAX, 10, Phase::Moved)),
+            Some(Scroll {
+                delta: TouchPoint::new(i32::MAX, 0),
+            })
+        );
+    }
+
+    #[test]
+    fn divergent_movement_does_not_scroll() {
+        let mut recognizer = ScrollRecognizer::new();
+        recognizer.update(&finger(0, 100, 100, Phase::Started));
+        recognizer.update(&finger(1, 200, 100, Phase::Started));
+        for frame in 1..=3 {
+            recognizer.update(&finger(0, 100 - frame * 10, 100, Phase::Moved));
+            assert_eq!(
+                recognizer.update(&finger(1, 200 + frame * 10, 100, Phase::Moved)),
+                None
+            );
+        }
+    }
+}
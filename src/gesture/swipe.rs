@@ -0,0 +1,166 @@
+use super::Direction;
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// A detected swipe
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Swipe {
+    /// Direction of the dominant axis of travel
+    pub direction: Direction,
+    /// Straight-line distance from start to end, in pixels
+    pub distance: f32,
+    /// Average speed over the swipe, in pixels per second
+    pub velocity: f32,
+}
+
+/// Thresholds for recognizing swipes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SwipeConfig {
+    /// Minimum straight-line distance, in pixels, a touch must travel
+    pub min_distance: u32,
+    /// Maximum duration, in milliseconds, between a touch starting and ending
+    pub max_duration_ms: u32,
+}
+
+impl Default for SwipeConfig {
+    fn default() -> Self {
+        Self {
+            min_distance: 50,
+            max_duration_ms: 500,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Start {
+    location: TouchPoint,
+    timestamp_ms: u32,
+}
+
+/// Recognizes directional swipes
+///
+/// A swipe is reported when a touch ends at least [`SwipeConfig::min_distance`] from
+/// where it started, within [`SwipeConfig::max_duration_ms`].
+#[derive(Debug, Clone)]
+pub struct SwipeRecognizer<const N: usize> {
+    config: SwipeConfig,
+    starts: IdMap<Start, N>,
+}
+
+impl<const N: usize> SwipeRecognizer<N> {
+    /// Create a new swipe recognizer
+    #[must_use]
+    pub fn new(config: SwipeConfig) -> Self {
+        Self {
+            config,
+            starts: IdMap::new(),
+        }
+    }
+
+    /// Feed a touch event to the recognizer, returning a swipe if one was completed
+    #[expect(clippy::cast_precision_loss)]
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) -> Option<Swipe> {
+        match touch.phase {
+            Phase::Started => {
+                let start = Start {
+                    location: touch.location,
+                    timestamp_ms,
+                };
+                _ = self.starts.insert(touch.id, start);
+                None
+            }
+            Phase::Ended => {
+                let start = self.starts.remove(touch.id)?;
+                let duration_ms = timestamp_ms.wrapping_sub(start.timestamp_ms);
+                let min_distance = i64::from(self.config.min_distance);
+                if duration_ms > self.config.max_duration_ms
+                    || start.location.distance_squared(&touch.location) < min_distance.pow(2)
+                {
+                    return None;
+                }
+                let distance = start.location.distance(&touch.location);
+                Some(Swipe {
+                    direction: touch
+                        .location
+                        .saturating_sub(start.location)
+                        .dominant_direction(),
+                    distance,
+                    velocity: distance * 1000.0 / duration_ms.max(1) as f32,
+                })
+            }
+            Phase::Cancelled => {
+                self.starts.remove(touch.id);
+                None
+            }
+            Phase::Moved | Phase::Stationary | Phase::Hovering(_) => None,
+        }
+    }
+}
+
+impl<const N: usize> Default for SwipeRecognizer<N> {
+    fn default() -> Self {
+        Self::new(SwipeConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn rightward_swipe() {
+        let mut recognizer = SwipeRecognizer::<2>::default();
+        recognizer.update(&finger(0, 10, 100, Phase::Started), 0);
+        recognizer.update(&finger(0, 60, 102, Phase::Moved), 50);
+        let swipe = recognizer
+            .update(&finger(0, 110, 100, Phase::Ended), 100)
+            .unwrap();
+        assert_eq!(swipe.direction, Direction::Right);
+        assert!((swipe.distance - 100.0).abs() < 0.001);
+        assert!((swipe.velocity - 1000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn swipe_across_extreme_coordinates() {
+        let mut recognizer = SwipeRecognizer::<2>::default();
+        recognizer.update(&finger(0, i32::MAX, i32::MIN, Phase::Started), 0);
+        let swipe = recognizer
+            .update(&finger(0, i32::MIN, i32::MIN, Phase::Ended), 100)
+            .unwrap();
+        assert_eq!(swipe.direction, Direction::Left);
+    }
+
+    #[test]
+    fn slow_drag_is_not_a_swipe() {
+        let mut recognizer = SwipeRecognizer::<2>::default();
+        recognizer.update(&finger(0, 10, 100, Phase::Started), 0);
+        assert_eq!(
+            recognizer.update(&finger(0, 210, 100, Phase::Ended), 2000),
+            None
+        );
+    }
+
+    #[test]
+    fn short_drag_is_not_a_swipe() {
+        let mut recognizer = SwipeRecognizer::<2>::default();
+        recognizer.update(&finger(0, 10, 100, Phase::Started), 0);
+        assert_eq!(
+            recognizer.update(&finger(0, 40, 100, Phase::Ended), 100),
+            None
+        );
+    }
+
+    #[test]
+    fn diagonal_resolves_to_dominant_axis() {
+        let mut recognizer = SwipeRecognizer::<2>::default();
+        recognizer.update(&finger(0, 100, 200, Phase::Started), 0);
+        let swipe = recognizer
+            .update(&finger(0, 140, 100, Phase::Ended), 100)
+            .unwrap();
+        assert_eq!(swipe.direction, Direction::Up);
+    }
+}
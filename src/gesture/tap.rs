@@ -0,0 +1,239 @@
+use super::within;
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap};
+
+/// A detected tap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Tap {
+    /// Location where the tap started
+    pub location: TouchPoint,
+    /// Whether this was a single or double tap
+    pub kind: TapKind,
+}
+
+/// The number of taps in quick succession
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TapKind {
+    /// A lone tap
+    Single,
+    /// The second of two taps in quick succession
+    Double,
+}
+
+/// Thresholds for recognizing taps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TapConfig {
+    /// Maximum distance, in pixels, a touch may move before it is no longer a tap
+    pub movement_threshold: u32,
+    /// Maximum duration, in milliseconds, between a touch starting and ending
+    pub max_duration_ms: u32,
+    /// Maximum time, in milliseconds, between the end of one tap and the end of the next
+    /// for them to form a double tap
+    pub double_tap_interval_ms: u32,
+    /// Maximum distance, in pixels, between two taps for them to form a double tap
+    pub double_tap_distance: u32,
+}
+
+impl Default for TapConfig {
+    fn default() -> Self {
+        Self {
+            movement_threshold: 10,
+            max_duration_ms: 300,
+            double_tap_interval_ms: 300,
+            double_tap_distance: 40,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Start {
+    location: TouchPoint,
+    timestamp_ms: u32,
+}
+
+/// Recognizes single and double taps
+///
+/// A tap is a touch that starts and ends without moving further than
+/// [`TapConfig::movement_threshold`] and within [`TapConfig::max_duration_ms`].
+/// Single taps are reported as soon as the touch ends, so the first tap of a double tap
+/// is also reported as a [`TapKind::Single`].
+#[derive(Debug, Clone)]
+pub struct TapRecognizer<const N: usize> {
+    config: TapConfig,
+    starts: IdMap<Start, N>,
+    last_tap: Option<Start>,
+}
+
+impl<const N: usize> TapRecognizer<N> {
+    /// Create a new tap recognizer
+    #[must_use]
+    pub fn new(config: TapConfig) -> Self {
+        Self {
+            config,
+            starts: IdMap::new(),
+            last_tap: None,
+        }
+    }
+
+    /// Feed a touch event to the recognizer, returning a tap if one was completed
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) -> Option<Tap> {
+        match touch.phase {
+            Phase::Started => {
+                let start = Start {
+                    location: touch.location,
+                    timestamp_ms,
+                };
+                _ = self.starts.insert(touch.id, start);
+                None
+            }
+            Phase::Moved => {
+                let start = self.starts.get(touch.id)?;
+                if !within(
+                    start.location,
+                    touch.location,
+                    self.config.movement_threshold,
+                ) {
+                    self.starts.remove(touch.id);
+                }
+                None
+            }
+            Phase::Ended => {
+                let start = self.starts.remove(touch.id)?;
+                let duration = timestamp_ms.wrapping_sub(start.timestamp_ms);
+                if duration > self.config.max_duration_ms
+                    || !within(
+                        start.location,
+                        touch.location,
+                        self.config.movement_threshold,
+                    )
+                {
+                    return None;
+                }
+                Some(self.complete_tap(start.location, timestamp_ms))
+            }
+            Phase::Cancelled => {
+                self.starts.remove(touch.id);
+                None
+            }
+            Phase::Stationary | Phase::Hovering(_) => None,
+        }
+    }
+
+    fn complete_tap(&mut self, location: TouchPoint, timestamp_ms: u32) -> Tap {
+        let is_double = self.last_tap.is_some_and(|last| {
+            timestamp_ms.wrapping_sub(last.timestamp_ms) <= self.config.double_tap_interval_ms
+                && within(last.location, location, self.config.double_tap_distance)
+        });
+
+        if is_double {
+            self.last_tap = None;
+            Tap {
+                location,
+                kind: TapKind::Double,
+            }
+        } else {
+            self.last_tap = Some(Start {
+                location,
+                timestamp_ms,
+            });
+            Tap {
+                location,
+                kind: TapKind::Single,
+            }
+        }
+    }
+}
+
+impl<const N: usize> Default for TapRecognizer<N> {
+    fn default() -> Self {
+        Self::new(TapConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn clean_tap() {
+        let mut recognizer = TapRecognizer::<2>::default();
+        assert_eq!(
+            recognizer.update(&finger(0, 50, 50, Phase::Started), 1000),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 52, 51, Phase::Moved), 1050),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 53, 51, Phase::Ended), 1100),
+            Some(Tap {
+                location: TouchPoint::new(50, 50),
+                kind: TapKind::Single
+            })
+        );
+    }
+
+    #[test]
+    fn moved_too_far_is_rejected() {
+        let mut recognizer = TapRecognizer::<2>::default();
+        recognizer.update(&finger(0, 50, 50, Phase::Started), 1000);
+        recognizer.update(&finger(0, 80, 50, Phase::Moved), 1050);
+        recognizer.update(&finger(0, 50, 50, Phase::Moved), 1080);
+        assert_eq!(
+            recognizer.update(&finger(0, 50, 50, Phase::Ended), 1100),
+            None
+        );
+    }
+
+    #[test]
+    fn held_too_long_is_rejected() {
+        let mut recognizer = TapRecognizer::<2>::default();
+        recognizer.update(&finger(0, 50, 50, Phase::Started), 1000);
+        assert_eq!(
+            recognizer.update(&finger(0, 50, 50, Phase::Ended), 1500),
+            None
+        );
+    }
+
+    #[test]
+    fn double_tap() {
+        let mut recognizer = TapRecognizer::<2>::default();
+        recognizer.update(&finger(0, 50, 50, Phase::Started), 1000);
+        let first = recognizer.update(&finger(0, 50, 50, Phase::Ended), 1080);
+        assert_eq!(first.map(|tap| tap.kind), Some(TapKind::Single));
+
+        recognizer.update(&finger(1, 55, 48, Phase::Started), 1200);
+        let second = recognizer.update(&finger(1, 55, 48, Phase::Ended), 1260);
+        assert_eq!(second.map(|tap| tap.kind), Some(TapKind::Double));
+
+        recognizer.update(&finger(0, 55, 48, Phase::Started), 1300);
+        let third = recognizer.update(&finger(0, 55, 48, Phase::Ended), 1350);
+        assert_eq!(third.map(|tap| tap.kind), Some(TapKind::Single));
+    }
+
+    #[test]
+    fn simultaneous_touches_are_tracked_separately() {
+        let mut recognizer = TapRecognizer::<2>::default();
+        recognizer.update(&finger(0, 50, 50, Phase::Started), 1000);
+        recognizer.update(&finger(1, 200, 200, Phase::Started), 1010);
+        recognizer.update(&finger(1, 300, 200, Phase::Moved), 1020);
+        assert_eq!(
+            recognizer.update(&finger(1, 300, 200, Phase::Ended), 1030),
+            None
+        );
+        assert_eq!(
+            recognizer.update(&finger(0, 50, 50, Phase::Ended), 1040),
+            Some(Tap {
+                location: TouchPoint::new(50, 50),
+                kind: TapKind::Single
+            })
+        );
+    }
+}
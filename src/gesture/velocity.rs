@@ -0,0 +1,179 @@
+use crate::{
+    Phase, Touch, TouchPoint,
+    id_map::IdMap,
+    ring::{RingBuffer, Sample},
+};
+
+/// Number of recent samples kept per touch
+const SAMPLES: usize = 8;
+
+type History = RingBuffer<Sample<TouchPoint>, SAMPLES>;
+
+const EMPTY: History = RingBuffer::new(Sample {
+    value: TouchPoint::ORIGIN,
+    timestamp_ms: 0,
+});
+
+#[expect(clippy::cast_precision_loss)]
+fn velocity(history: &History, window_ms: u32) -> Option<(f32, f32)> {
+    let (newest, oldest, elapsed_ms) = history.span(window_ms)?;
+    let dx = i64::from(newest.x) - i64::from(oldest.x);
+    let dy = i64::from(newest.y) - i64::from(oldest.y);
+    let per_second = 1000.0 / elapsed_ms as f32;
+    Some((dx as f32 * per_second, dy as f32 * per_second))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Released {
+    velocity: Option<(f32, f32)>,
+    timestamp_ms: u32,
+}
+
+/// Estimates the velocity of touches from their recent movement
+///
+/// Velocity is computed from the displacement over the most recent samples within a
+/// time window, which smooths over irregular sampling. History is cleared when a touch
+/// ends or is cancelled, but its final velocity remains available from
+/// [`VelocityTracker::flick`] until the ID is reused, e.g. for inertial scrolling. The
+/// final velocities of up to `N` released touches are kept, and the oldest is forgotten
+/// to make room for another.
+#[derive(Debug, Clone)]
+pub struct VelocityTracker<const N: usize> {
+    window_ms: u32,
+    histories: IdMap<History, N>,
+    released: IdMap<Released, N>,
+}
+
+impl<const N: usize> VelocityTracker<N> {
+    /// Create a tracker that estimates velocity over the last `window_ms` milliseconds
+    #[must_use]
+    pub fn new(window_ms: u32) -> Self {
+        Self {
+            window_ms,
+            histories: IdMap::new(),
+            released: IdMap::new(),
+        }
+    }
+
+    /// Feed a touch event to the tracker
+    pub fn update(&mut self, touch: &Touch, timestamp_ms: u32) {
+        let sample = Sample {
+            value: touch.location,
+            timestamp_ms,
+        };
+        match touch.phase {
+            Phase::Started => {
+                self.released.remove(touch.id);
+                let mut history = EMPTY;
+                history.push(sample);
+                _ = self.histories.insert(touch.id, history);
+            }
+            Phase::Moved | Phase::Stationary => {
+                if let Some(history) = self.histories.get_mut(touch.id) {
+                    history.push(sample);
+                }
+            }
+            Phase::Ended | Phase::Cancelled => {
+                if let Some(mut history) = self.histories.remove(touch.id) {
+                    history.push(sample);
+                    let released = Released {
+                        velocity: velocity(&history, self.window_ms),
+                        timestamp_ms,
+                    };
+                    self.released
+                        .insert_evicting(touch.id, released, |released| {
+                            timestamp_ms.wrapping_sub(released.timestamp_ms)
+                        });
+                }
+            }
+            Phase::Hovering(_) => {}
+        }
+    }
+
+    /// Returns the current velocity of an active touch in pixels per second
+    ///
+    /// Returns `None` if the touch is not being tracked, or has too few samples.
+    #[must_use]
+    pub fn velocity(&self, id: u8) -> Option<(f32, f32)> {
+        velocity(self.histories.get(id)?, self.window_ms)
+    }
+
+    /// Returns the velocity of a touch at the moment it ended, in pixels per second
+    #[must_use]
+    pub fn flick(&self, id: u8) -> Option<(f32, f32)> {
+        self.released.get(id)?.velocity
+    }
+}
+
+impl<const N: usize> Default for VelocityTracker<N> {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::finger;
+
+    #[test]
+    fn constant_velocity_drag() {
+        let mut tracker = VelocityTracker::<2>::default();
+        tracker.update(&finger(0, 0, 0, Phase::Started), 0);
+        assert_eq!(tracker.velocity(0), None);
+        for step in 1..20 {
+            tracker.update(
+                &finger(0, step * 10, step * -5, Phase::Moved),
+                step.cast_unsigned() * 10,
+            );
+        }
+        let (vx, vy) = tracker.velocity(0).unwrap();
+        assert!((vx - 1000.0).abs() < 0.1);
+        assert!((vy + 500.0).abs() < 0.1);
+
+        tracker.update(&finger(0, 200, -100, Phase::Ended), 200);
+        assert_eq!(tracker.velocity(0), None);
+        let (vx, vy) = tracker.flick(0).unwrap();
+        assert!((vx - 1000.0).abs() < 0.1);
+        assert!((vy + 500.0).abs() < 0.1);
+
+        tracker.update(&finger(0, 0, 0, Phase::Started), 300);
+        assert_eq!(tracker.flick(0), None);
+    }
+
+    #[test]
+    fn flicks_of_many_touches() {
+        let mut tracker = VelocityTracker::<2>::default();
+        for id in 0..5u8 {
+            let start = u32::from(id) * 100;
+            tracker.update(&finger(id, 0, 0, Phase::Started), start);
+            tracker.update(&finger(id, 10, 0, Phase::Moved), start + 10);
+            tracker.update(&finger(id, 20, 0, Phase::Ended), start + 20);
+            let (vx, _) = tracker.flick(id).unwrap();
+            assert!((vx - 1000.0).abs() < 0.1, "{id}: {vx}");
+        }
+        assert!(tracker.flick(3).is_some());
+        assert_eq!(tracker.flick(2), None);
+    }
+
+    #[test]
+    fn drag_across_extreme_coordinates() {
+        let mut tracker = VelocityTracker::<2>::default();
+        tracker.update(&finger(0, i32::MIN, i32::MAX, Phase::Started), 0);
+        tracker.update(&finger(0, i32::MAX, i32::MIN, Phase::Moved), 100);
+        let (vx, vy) = tracker.velocity(0).unwrap();
+        assert!((vx / 42_949_672_950.0 - 1.0).abs() < 1e-6, "{vx}");
+        assert!((vy / 42_949_672_950.0 + 1.0).abs() < 1e-6, "{vy}");
+    }
+
+    #[test]
+    fn stationary_hold() {
+        let mut tracker = VelocityTracker::<2>::default();
+        tracker.update(&finger(0, 50, 50, Phase::Started), 0);
+        for timestamp in [16, 33, 50, 66] {
+            tracker.update(&finger(0, 50, 50, Phase::Moved), timestamp);
+        }
+        let (vx, vy) = tracker.velocity(0).unwrap();
+        assert!(vx.abs() < 0.001 && vy.abs() < 0.001);
+    }
+}
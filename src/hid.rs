@@ -0,0 +1,207 @@
+//! Conversion of touches into USB HID digitizer reports
+
+use crate::{Phase, Touch};
+
+/// Largest coordinate in the HID logical range used by [`DigitizerReport`]
+pub const LOGICAL_MAX: u16 = 0x7FFF;
+
+/// A single-contact USB HID digitizer report
+///
+/// The report serializes to 8 bytes with [`DigitizerReport::to_bytes`]:
+///
+/// | Byte | Field                                   |
+/// |------|-----------------------------------------|
+/// | 0    | Status bits ([`TIP_SWITCH`](Self::TIP_SWITCH), [`IN_RANGE`](Self::IN_RANGE), [`ERASER`](Self::ERASER)) |
+/// | 1    | Contact identifier                      |
+/// | 2–3  | X, little-endian, `0..=LOGICAL_MAX`     |
+/// | 4–5  | Y, little-endian, `0..=LOGICAL_MAX`     |
+/// | 6–7  | Tip pressure, little-endian, in grams   |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DigitizerReport {
+    /// Status bits
+    pub status: u8,
+    /// Contact identifier, taken from the touch ID
+    pub contact_id: u8,
+    /// X coordinate in the HID logical range
+    pub x: u16,
+    /// Y coordinate in the HID logical range
+    pub y: u16,
+    /// Tip pressure in grams, or 0 if not reported or the tip is not in contact
+    pub tip_pressure: u16,
+}
+
+impl DigitizerReport {
+    /// Status bit set while the tool is in contact with the screen
+    pub const TIP_SWITCH: u8 = 1 << 0;
+    /// Status bit set while the tool is in contact with or hovering above the screen
+    pub const IN_RANGE: u8 = 1 << 1;
+    /// Status bit set while the eraser end of a stylus is in contact, HID usage Eraser
+    /// (0x45)
+    ///
+    /// [`TIP_SWITCH`](Self::TIP_SWITCH) is set as well, so hosts that ignore this bit
+    /// see the eraser as a pen contact.
+    pub const ERASER: u8 = 1 << 2;
+
+    /// Returns `true` if the tip switch bit is set
+    #[must_use]
+    pub fn tip_switch(&self) -> bool {
+        self.status & Self::TIP_SWITCH != 0
+    }
+
+    /// Returns `true` if the in-range bit is set
+    #[must_use]
+    pub fn in_range(&self) -> bool {
+        self.status & Self::IN_RANGE != 0
+    }
+
+    /// Returns `true` if the eraser bit is set
+    #[must_use]
+    pub fn eraser(&self) -> bool {
+        self.status & Self::ERASER != 0
+    }
+
+    /// Serialize the report into its wire format
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let [x0, x1] = self.x.to_le_bytes();
+        let [y0, y1] = self.y.to_le_bytes();
+        let [p0, p1] = self.tip_pressure.to_le_bytes();
+        [self.status, self.contact_id, x0, x1, y0, y1, p0, p1]
+    }
+}
+
+impl Touch {
+    /// Convert the touch into a HID digitizer report
+    ///
+    /// `panel` is the `(width, height)` of the touch panel in pixels. Locations are
+    /// scaled so the first pixel maps to 0 and the last to [`LOGICAL_MAX`], and points
+    /// off the panel are clamped to its edges. Tip pressure is only reported while the
+    /// tool is in contact, so a hovering stylus sends 0.
+    #[must_use]
+    pub fn to_hid_report(&self, panel: (u16, u16)) -> DigitizerReport {
+        let status = match self.phase {
+            Phase::Started | Phase::Moved | Phase::Stationary if self.tool.is_eraser() => {
+                DigitizerReport::TIP_SWITCH | DigitizerReport::IN_RANGE | DigitizerReport::ERASER
+            }
+            Phase::Started | Phase::Moved | Phase::Stationary => {
+                DigitizerReport::TIP_SWITCH | DigitizerReport::IN_RANGE
+            }
+            Phase::Hovering(_) => DigitizerReport::IN_RANGE,
+            Phase::Ended | Phase::Cancelled => 0,
+        };
+        let tip_pressure = if status & DigitizerReport::TIP_SWITCH == 0 {
+            0
+        } else {
+            self.tool.pressure().unwrap_or(0)
+        };
+        DigitizerReport {
+            status,
+            contact_id: self.id,
+            x: to_logical(self.location.x, panel.0),
+            y: to_logical(self.location.y, panel.1),
+            tip_pressure,
+        }
+    }
+}
+
+/// Scale a pixel coordinate on an axis of `size` pixels into the HID logical range
+fn to_logical(value: i32, size: u16) -> u16 {
+    let last = i64::from(size.saturating_sub(1));
+    if last == 0 {
+        return 0;
+    }
+    let value = i64::from(value).clamp(0, last);
+    // `value <= last`, so the quotient is at most LOGICAL_MAX
+    u16::try_from(value * i64::from(LOGICAL_MAX) / last).unwrap_or(LOGICAL_MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tool, TouchPoint, UnitAngle};
+
+    #[test]
+    fn pressed_finger_sets_tip_and_range() {
+        let touch = Touch::new(3, TouchPoint::new(799, 0), Phase::Moved, Tool::Finger);
+        let report = touch.to_hid_report((800, 480));
+
+        assert!(report.tip_switch());
+        assert!(report.in_range());
+        assert_eq!(
+            report.to_bytes(),
+            [0b11, 3, 0xFF, 0x7F, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn hovering_stylus_is_in_range_without_tip() {
+        let touch = Touch::new(
+            1,
+            TouchPoint::new(-20, 240),
+            Phase::Hovering(Some(5)),
+            Tool::Stylus {
+                pressure: Some(0x0123),
+                barrel_pressure: None,
+                tilt: None,
+                azimuth: Some(UnitAngle::from_degrees(90)),
+            },
+        );
+        let report = touch.to_hid_report((800, 481));
+
+        assert!(!report.tip_switch());
+        assert!(report.in_range());
+        assert_eq!(
+            report.to_bytes(),
+            [0b10, 1, 0x00, 0x00, 0xFF, 0x3F, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn pressure_is_only_sent_in_contact() {
+        let stylus = |phase| Touch::stylus(2, TouchPoint::new(0, 0), Some(0x0123), phase);
+        assert_eq!(
+            stylus(Phase::Moved).to_hid_report((800, 480)).tip_pressure,
+            0x0123
+        );
+        assert_eq!(
+            stylus(Phase::Hovering(None))
+                .to_hid_report((800, 480))
+                .tip_pressure,
+            0
+        );
+        assert_eq!(
+            stylus(Phase::Ended).to_hid_report((800, 480)).tip_pressure,
+            0
+        );
+    }
+
+    #[test]
+    fn eraser_sets_eraser_bit_in_contact() {
+        let eraser = |phase| {
+            let tool = Tool::Eraser {
+                pressure: Some(0x0040),
+                tilt: None,
+                azimuth: None,
+            };
+            Touch::new(4, TouchPoint::new(0, 0), phase, tool).to_hid_report((800, 480))
+        };
+        let report = eraser(Phase::Moved);
+        assert!(report.eraser() && report.tip_switch() && report.in_range());
+        assert_eq!(report.to_bytes(), [0b111, 4, 0, 0, 0, 0, 0x40, 0x00]);
+
+        let report = eraser(Phase::Hovering(None));
+        assert!(!report.eraser() && report.in_range());
+
+        let stylus = Touch::stylus(4, TouchPoint::new(0, 0), None, Phase::Moved);
+        assert!(!stylus.to_hid_report((800, 480)).eraser());
+    }
+
+    #[test]
+    fn ended_touch_clears_status() {
+        let touch = Touch::new(0, TouchPoint::new(10, 10), Phase::Ended, Tool::Finger);
+        assert_eq!(touch.to_hid_report((1, 1)).status, 0);
+        assert_eq!(touch.to_hid_report((1, 1)).x, 0);
+    }
+}
@@ -0,0 +1,368 @@
+//! Trajectories and distances travelled by touches, and simplification of paths
+
+use crate::{Phase, Touch, TouchPoint, id_map::IdMap, ring::RingBuffer};
+
+/// The most recent `N` locations of a touch
+type Path<const N: usize> = RingBuffer<TouchPoint, N>;
+
+/// Records the last `N` locations of each active touch
+///
+/// Up to `T` simultaneous touches are tracked by ID; additional touches are ignored.
+/// A path starts when a touch starts and grows with each [`Phase::Moved`] event, with
+/// the oldest points evicted once it holds `N`. [`Phase::Stationary`] events are not
+/// recorded, since the touch has not moved. The path of a touch is cleared when it
+/// ends or is cancelled.
+#[derive(Debug, Clone)]
+pub struct TouchHistory<const N: usize, const T: usize = 10> {
+    paths: IdMap<Path<N>, T>,
+}
+
+impl<const N: usize, const T: usize> TouchHistory<N, T> {
+    /// Create an empty history
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            paths: IdMap::new(),
+        }
+    }
+
+    /// Feed a touch event to the history
+    pub fn update(&mut self, touch: &Touch) {
+        match touch.phase {
+            Phase::Started => {
+                let mut path = Path::new(TouchPoint::ORIGIN);
+                path.push(touch.location);
+                _ = self.paths.insert(touch.id, path);
+            }
+            Phase::Moved => {
+                if let Some(path) = self.paths.get_mut(touch.id) {
+                    path.push(touch.location);
+                } else {
+                    // The start was missed, so begin the path here
+                    let mut path = Path::new(TouchPoint::ORIGIN);
+                    path.push(touch.location);
+                    _ = self.paths.insert(touch.id, path);
+                }
+            }
+            Phase::Ended | Phase::Cancelled => {
+                self.paths.remove(touch.id);
+            }
+            Phase::Stationary | Phase::Hovering(_) => {}
+        }
+    }
+
+    /// Returns the recorded locations of an active touch, from oldest to newest
+    ///
+    /// The path is empty if the touch is not being tracked.
+    pub fn path(&self, id: u8) -> impl Iterator<Item = TouchPoint> {
+        self.paths
+            .get(id)
+            .into_iter()
+            .flat_map(|path| path.newest_first().rev().copied())
+    }
+
+    /// Forget the paths of all touches
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+}
+
+impl<const N: usize, const T: usize> Default for TouchHistory<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Travel {
+    last: TouchPoint,
+    length: f32,
+}
+
+/// Accumulates the total distance travelled by each touch
+///
+/// Up to `N` simultaneous touches are tracked by ID; additional touches are ignored.
+/// Each event adds the straight-line distance from the previous location of the touch,
+/// so the length follows the path actually taken rather than the displacement from the
+/// start. The length restarts at 0 when a touch starts, and once a touch ends or is
+/// cancelled its final length remains available until the ID is reused. The final
+/// lengths of up to `N` touches are kept, and the oldest is forgotten to make room for
+/// another.
+#[derive(Debug, Clone)]
+pub struct PathLength<const N: usize> {
+    active: IdMap<Travel, N>,
+    /// Final lengths, with the value of `releases` when each touch ended
+    finished: IdMap<(u32, f32), N>,
+    releases: u32,
+}
+
+impl<const N: usize> PathLength<N> {
+    /// Create a tracker with no touches
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            active: IdMap::new(),
+            finished: IdMap::new(),
+            releases: 0,
+        }
+    }
+
+    /// Feed a touch event to the tracker
+    pub fn update(&mut self, touch: &Touch) {
+        match touch.phase {
+            Phase::Started => {
+                self.finished.remove(touch.id);
+                let travel = Travel {
+                    last: touch.location,
+                    length: 0.0,
+                };
+                _ = self.active.insert(touch.id, travel);
+            }
+            Phase::Moved | Phase::Stationary => {
+                if let Some(travel) = self.active.get_mut(touch.id) {
+                    travel.length += travel.last.distance(&touch.location);
+                    travel.last = touch.location;
+                }
+            }
+            Phase::Ended | Phase::Cancelled => {
+                if let Some(travel) = self.active.remove(touch.id) {
+                    let length = travel.length + travel.last.distance(&touch.location);
+                    let releases = self.releases;
+                    self.releases = releases.wrapping_add(1);
+                    self.finished.insert_evicting(
+                        touch.id,
+                        (releases, length),
+                        |&(released, _)| releases.wrapping_sub(released),
+                    );
+                }
+            }
+            Phase::Hovering(_) => {}
+        }
+    }
+
+    /// Returns the distance travelled by a touch, in pixels
+    ///
+    /// Returns `None` if the touch has not been seen since it started.
+    #[must_use]
+    pub fn length(&self, id: u8) -> Option<f32> {
+        self.active
+            .get(id)
+            .map(|travel| travel.length)
+            .or_else(|| self.finished.get(id).map(|&(_, length)| length))
+    }
+}
+
+impl<const N: usize> Default for PathLength<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Simplify a path with the Ramer–Douglas–Peucker algorithm
+///
+/// Points are dropped while every dropped point lies within `epsilon` pixels of the
+/// straight line between the kept points on either side of it. The first and last
+/// points are always kept. The kept points are written to `out` in order, and their
+/// count is returned; if `out` is too short, the path is truncated to fit, so an `out`
+/// as long as `points` always suffices. A negative `epsilon` behaves as 0.
+///
+/// Distances are computed exactly in integer arithmetic, for any points within
+/// [`i32::MAX`] of each other on both axes. No allocation or recursion is used, at the
+/// cost of rescanning the path from the start to find each kept point, so simplifying
+/// a path of `n` points to `k` takes O(`k` · `n` log `n`) time in typical cases.
+pub fn simplify_path(points: &[TouchPoint], epsilon: i64, out: &mut [TouchPoint]) -> usize {
+    let Some(last) = points.len().checked_sub(1) else {
+        return 0;
+    };
+    let epsilon_squared = u128::from(epsilon.max(0).unsigned_abs()).pow(2);
+    let mut count = 0;
+    let mut anchor = 0;
+    while let Some(slot) = out.get_mut(count) {
+        *slot = points[anchor];
+        count += 1;
+        if anchor == last {
+            break;
+        }
+        anchor = next_kept(points, anchor, epsilon_squared);
+    }
+    count
+}
+
+/// Returns the index of the next point kept after the kept point at `anchor`
+///
+/// This descends the subdivision from the whole path to the segment starting at
+/// `anchor` that needs no further subdivision, and returns its end.
+fn next_kept(points: &[TouchPoint], anchor: usize, epsilon_squared: u128) -> usize {
+    let (mut start, mut end) = (0, points.len() - 1);
+    while let Some(offset) = farthest_beyond(&points[start..=end], epsilon_squared) {
+        let split = start + offset;
+        if anchor < split {
+            end = split;
+        } else {
+            start = split;
+        }
+    }
+    end
+}
+
+/// Returns the index of the interior point farthest from the line between the first
+/// and last points of `segment`, if it is more than `epsilon` from the line
+fn farthest_beyond(segment: &[TouchPoint], epsilon_squared: u128) -> Option<usize> {
+    let (&first, &last) = (segment.first()?, segment.last()?);
+    let (dx, dy) = (
+        i128::from(last.x) - i128::from(first.x),
+        i128::from(last.y) - i128::from(first.y),
+    );
+    let length_squared = (dx * dx + dy * dy).unsigned_abs();
+    // Comparing squared distances scaled by the squared length of the line avoids
+    // division. Saturation only rounds the threshold up toward its true value.
+    let threshold = epsilon_squared.saturating_mul(length_squared.max(1));
+
+    let mut farthest = None;
+    let mut max_distance = threshold;
+    for (index, point) in segment.iter().enumerate().take(segment.len() - 1).skip(1) {
+        let (px, py) = (
+            i128::from(point.x) - i128::from(first.x),
+            i128::from(point.y) - i128::from(first.y),
+        );
+        let distance = if length_squared == 0 {
+            // Degenerate line, so measure from the endpoint
+            (px * px + py * py).unsigned_abs()
+        } else {
+            (dx * py - dy * px).unsigned_abs().saturating_pow(2)
+        };
+        if distance > max_distance {
+            farthest = Some(index);
+            max_distance = distance;
+        }
+    }
+    farthest
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::{Tool, test_util::finger};
+
+    fn xs(history: &TouchHistory<3>, id: u8) -> Vec<i32> {
+        history.path(id).map(|point| point.x).collect()
+    }
+
+    #[test]
+    fn accumulates_path_per_id() {
+        let mut history = TouchHistory::<3>::new();
+        history.update(&finger(0, 10, 0, Phase::Started));
+        history.update(&finger(1, 100, 0, Phase::Started));
+        history.update(&finger(0, 20, 0, Phase::Moved));
+        history.update(&finger(0, 20, 0, Phase::Stationary));
+        history.update(&finger(1, 110, 0, Phase::Moved));
+
+        assert_eq!(xs(&history, 0), [10, 20]);
+        assert_eq!(xs(&history, 1), [100, 110]);
+        assert!(xs(&history, 2).is_empty());
+
+        history.update(&finger(0, 30, 0, Phase::Ended));
+        assert!(xs(&history, 0).is_empty());
+        assert_eq!(xs(&history, 1), [100, 110]);
+    }
+
+    #[test]
+    fn evicts_oldest_points_past_capacity() {
+        let mut history = TouchHistory::<3>::new();
+        history.update(&finger(0, 0, 0, Phase::Started));
+        for x in 1..=4 {
+            history.update(&finger(0, x, 0, Phase::Moved));
+        }
+        assert_eq!(xs(&history, 0), [2, 3, 4]);
+
+        history.update(&finger(0, 50, 0, Phase::Started));
+        assert_eq!(xs(&history, 0), [50]);
+    }
+
+    #[test]
+    fn path_length_follows_segments() {
+        let mut lengths = PathLength::<2>::new();
+        assert_eq!(lengths.length(0), None);
+        drag(&mut lengths, &[(0, 0), (3, 4), (3, 4), (3, 14)]);
+        assert!((lengths.length(0).unwrap() - 15.0).abs() < 0.001);
+
+        // The return leg counts, even though it ends where it started
+        lengths.update(&finger(0, 0, 0, Phase::Ended));
+        let returned = 15.0 + 14.0_f32.hypot(3.0);
+        assert!((lengths.length(0).unwrap() - returned).abs() < 0.001);
+
+        lengths.update(&finger(0, 50, 0, Phase::Started));
+        assert_eq!(lengths.length(0), Some(0.0));
+    }
+
+    #[test]
+    fn path_lengths_of_many_touches() {
+        let mut lengths = PathLength::<2>::new();
+        for id in 0..4 {
+            lengths.update(&finger(id, 0, 0, Phase::Started));
+            lengths.update(&finger(id, 20, 0, Phase::Ended));
+            assert_eq!(lengths.length(id), Some(20.0), "{id}");
+        }
+        assert_eq!(lengths.length(2), Some(20.0));
+        assert_eq!(lengths.length(1), None);
+    }
+
+    fn drag(lengths: &mut PathLength<2>, points: &[(i32, i32)]) {
+        for (index, &(x, y)) in points.iter().enumerate() {
+            let phase = if index == 0 {
+                Phase::Started
+            } else {
+                Phase::Moved
+            };
+            lengths.update(&Touch::new(0, TouchPoint::new(x, y), phase, Tool::Finger));
+        }
+    }
+
+    fn simplified(points: &[TouchPoint], epsilon: i64) -> Vec<TouchPoint> {
+        let mut out = [TouchPoint::new(0, 0); 16];
+        let count = simplify_path(points, epsilon, &mut out);
+        out[..count].to_vec()
+    }
+
+    #[test]
+    fn straight_line_collapses_to_endpoints() {
+        let line: Vec<_> = (0..=10).map(|i| TouchPoint::new(i * 3, i)).collect();
+        assert_eq!(
+            simplified(&line, 1),
+            [TouchPoint::new(0, 0), TouchPoint::new(30, 10)]
+        );
+
+        // Small wobbles within epsilon are dropped too
+        let wobbly = [(0, 0), (10, 1), (20, -1), (30, 1), (40, 0)].map(TouchPoint::from);
+        assert_eq!(
+            simplified(&wobbly, 2),
+            [TouchPoint::new(0, 0), TouchPoint::new(40, 0)]
+        );
+        assert_eq!(simplified(&wobbly[..1], 2), [TouchPoint::new(0, 0)]);
+        assert!(simplified(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn zigzag_keeps_corners() {
+        let zigzag = [
+            (0, 0),
+            (5, 5),
+            (10, 10),
+            (20, 0),
+            (30, 10),
+            (35, 5),
+            (40, 0),
+        ]
+        .map(TouchPoint::from);
+        let corners = [(0, 0), (10, 10), (20, 0), (30, 10), (40, 0)].map(TouchPoint::from);
+        assert_eq!(simplified(&zigzag, 2), corners);
+
+        let mut out = [TouchPoint::new(0, 0); 3];
+        assert_eq!(simplify_path(&zigzag, 2, &mut out), 3);
+        assert_eq!(out, corners[..3]);
+    }
+}
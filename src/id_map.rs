@@ -0,0 +1,130 @@
+//! Fixed-capacity map keyed by touch ID, used for per-touch state
+
+/// A map from touch ID to a value, with space for up to `N` entries
+///
+/// Entries are kept in insertion order while there are no removals, which lets the map
+/// double as a buffer of re-emitted touches.
+#[derive(Debug, Clone)]
+pub(crate) struct IdMap<V, const N: usize> {
+    entries: [Option<(u8, V)>; N],
+}
+
+impl<V, const N: usize> IdMap<V, N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [const { None }; N],
+        }
+    }
+
+    pub(crate) fn get(&self, id: u8) -> Option<&V> {
+        self.entries
+            .iter()
+            .flatten()
+            .find_map(|(key, value)| (*key == id).then_some(value))
+    }
+
+    pub(crate) fn get_mut(&mut self, id: u8) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .flatten()
+            .find_map(|(key, value)| (*key == id).then_some(value))
+    }
+
+    /// Inserts or replaces the value for `id`, returning the value if the map is full
+    pub(crate) fn insert(&mut self, id: u8, value: V) -> Result<(), V> {
+        if let Some(existing) = self.get_mut(id) {
+            *existing = value;
+            return Ok(());
+        }
+        match self.entries.iter_mut().find(|entry| entry.is_none()) {
+            Some(slot) => {
+                *slot = Some((id, value));
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Inserts or replaces the value for `id`, replacing the entry with the greatest
+    /// `age` if the map is full
+    pub(crate) fn insert_evicting<K: Ord>(
+        &mut self,
+        id: u8,
+        value: V,
+        mut age: impl FnMut(&V) -> K,
+    ) {
+        if let Err(value) = self.insert(id, value)
+            && let Some(oldest) = self
+                .entries
+                .iter_mut()
+                .flatten()
+                .max_by_key(|(_, value)| age(value))
+        {
+            *oldest = (id, value);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: u8) -> Option<V> {
+        self.entries
+            .iter_mut()
+            .find(|entry| matches!(entry, Some((key, _)) if *key == id))
+            .and_then(Option::take)
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries = [const { None }; N];
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().flatten().map(|(_, value)| value)
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (u8, &mut V)> {
+        self.entries
+            .iter_mut()
+            .flatten()
+            .map(|(id, value)| (*id, value))
+    }
+}
+
+impl<V, const N: usize> Default for IdMap<V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = IdMap::<u32, 2>::new();
+        assert_eq!(map.insert(5, 50), Ok(()));
+        assert_eq!(map.insert(9, 90), Ok(()));
+        assert_eq!(map.insert(1, 10), Err(10));
+        assert_eq!(map.insert(5, 55), Ok(()));
+        assert_eq!(map.get(5), Some(&55));
+        assert_eq!(map.get(1), None);
+        assert_eq!(map.remove(9), Some(90));
+        assert_eq!(map.remove(9), None);
+        assert_eq!(map.insert(9, 90), Ok(()));
+        let mut values = map.values();
+        assert_eq!(values.next(), Some(&55));
+        assert_eq!(values.next(), Some(&90));
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn insert_evicting_replaces_oldest() {
+        let mut map = IdMap::<u32, 2>::new();
+        map.insert_evicting(1, 30, |age| *age);
+        map.insert_evicting(2, 20, |age| *age);
+        map.insert_evicting(1, 10, |age| *age);
+        map.insert_evicting(3, 0, |age| *age);
+        assert_eq!(map.get(1), Some(&10));
+        assert_eq!(map.get(2), None);
+        assert_eq!(map.get(3), Some(&0));
+    }
+}
@@ -0,0 +1,191 @@
+//! Helpers for iterators over touches
+
+use core::iter::Filter;
+
+use crate::{Touch, TouchPoint};
+
+/// Iterator returned by the tool filters of [`TouchIteratorExt`]
+pub type ToolFilter<'a, I> = Filter<I, fn(&&'a Touch) -> bool>;
+
+/// Extension methods for iterators over touches, such as those returned by
+/// [`TouchInputDevice::touches`](crate::traits::TouchInputDevice::touches)
+pub trait TouchIteratorExt<'a>: Iterator<Item = &'a Touch> + Sized {
+    /// Keep only touches made with a stylus
+    fn only_stylus(self) -> ToolFilter<'a, Self> {
+        self.filter(|touch| touch.tool.is_stylus())
+    }
+
+    /// Keep only touches made with a finger, including those reporting a contact size
+    fn only_fingers(self) -> ToolFilter<'a, Self> {
+        self.filter(|touch| touch.tool.is_finger())
+    }
+
+    /// Keep only touches made with a virtual pointer
+    fn only_pointers(self) -> ToolFilter<'a, Self> {
+        self.filter(|touch| touch.tool.is_pointer())
+    }
+
+    /// Returns the active touch with the smallest ID
+    ///
+    /// Only touches in contact with the screen, as reported by
+    /// [`Phase::is_active`](crate::Phase::is_active), are considered, so hovering, ended,
+    /// and cancelled touches are skipped. If several active touches share the smallest ID,
+    /// the first one is returned. Returns `None` if there is no active touch.
+    fn primary(self) -> Option<&'a Touch> {
+        self.filter(|touch| touch.phase.is_active())
+            .reduce(|primary, touch| {
+                if touch.id < primary.id {
+                    touch
+                } else {
+                    primary
+                }
+            })
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a Touch>> TouchIteratorExt<'a> for I {}
+
+/// Returns the number of active touches
+///
+/// Only touches in contact with the screen, as reported by
+/// [`Phase::is_active`](crate::Phase::is_active), are counted.
+pub fn active_count<'a>(touches: impl Iterator<Item = &'a Touch>) -> usize {
+    touches.filter(|touch| touch.phase.is_active()).count()
+}
+
+/// Returns the average location of the active touches
+///
+/// Only touches in contact with the screen, as reported by
+/// [`Phase::is_active`](crate::Phase::is_active), are included. Locations are summed in [`i64`],
+/// so this does not overflow, and each component of the average is rounded toward zero.
+/// Returns `None` if there is no active touch.
+#[expect(clippy::cast_possible_truncation)]
+pub fn centroid<'a>(touches: impl Iterator<Item = &'a Touch>) -> Option<TouchPoint> {
+    let (count, x, y) = touches.filter(|touch| touch.phase.is_active()).fold(
+        (0i64, 0i64, 0i64),
+        |(count, x, y), touch| {
+            (
+                count + 1,
+                x + i64::from(touch.location.x),
+                y + i64::from(touch.location.y),
+            )
+        },
+    );
+    // The average of i32 values is always within the range of i32
+    (count > 0).then(|| TouchPoint {
+        x: (x / count) as i32,
+        y: (y / count) as i32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, PointerButton, Tool, TouchPoint, test_util::finger};
+
+    fn mixed_frame() -> [Touch; 5] {
+        let touch = |id, tool| Touch::new(id, TouchPoint::new(0, 0), Phase::Moved, tool);
+        [
+            touch(0, Tool::Finger),
+            touch(
+                1,
+                Tool::Stylus {
+                    pressure: None,
+                    barrel_pressure: None,
+                    tilt: None,
+                    azimuth: None,
+                },
+            ),
+            touch(
+                2,
+                Tool::Pointer {
+                    button: PointerButton::Primary,
+                    wheel_delta: None,
+                },
+            ),
+            touch(
+                3,
+                Tool::Contact {
+                    major_axis: 8,
+                    minor_axis: 6,
+                },
+            ),
+            touch(
+                4,
+                Tool::Stylus {
+                    pressure: Some(100),
+                    barrel_pressure: None,
+                    tilt: None,
+                    azimuth: None,
+                },
+            ),
+        ]
+    }
+
+    fn id_mask<'a>(touches: impl Iterator<Item = &'a Touch>) -> u8 {
+        touches.fold(0, |mask, touch| mask | 1 << touch.id)
+    }
+
+    #[test]
+    fn filters_by_tool() {
+        let frame = mixed_frame();
+        assert_eq!(id_mask(frame.iter().only_stylus()), 0b10010);
+        assert_eq!(id_mask(frame.iter().only_fingers()), 0b01001);
+        assert_eq!(id_mask(frame.iter().only_pointers()), 0b00100);
+    }
+
+    #[test]
+    fn primary_is_lowest_active_id() {
+        let frame = [
+            finger(4, 0, 0, Phase::Moved),
+            finger(1, 0, 0, Phase::Ended),
+            finger(2, 0, 0, Phase::Started),
+            finger(0, 0, 0, Phase::Hovering(None)),
+            finger(3, 0, 0, Phase::Moved),
+        ];
+
+        assert_eq!(frame.iter().primary(), Some(&frame[2]));
+        assert_eq!(frame[..2].iter().primary(), Some(&frame[0]));
+        assert_eq!(frame[1..2].iter().primary(), None);
+        assert_eq!([].iter().primary(), None);
+    }
+
+    #[test]
+    fn counts_active_touches() {
+        let frame = [
+            finger(0, 0, 0, Phase::Started),
+            finger(1, 0, 0, Phase::Ended),
+            finger(2, 0, 0, Phase::Moved),
+            finger(3, 0, 0, Phase::Hovering(Some(4))),
+            finger(4, 0, 0, Phase::Stationary),
+            finger(5, 0, 0, Phase::Cancelled),
+        ];
+        assert_eq!(active_count(frame.iter()), 3);
+        assert_eq!(active_count(frame[1..2].iter()), 0);
+    }
+
+    #[test]
+    fn centroid_averages_active_touches() {
+        let frame = [
+            finger(0, 10, 20, Phase::Started),
+            finger(1, 30, -40, Phase::Moved),
+            finger(2, 1000, 1000, Phase::Ended),
+            finger(3, 50, 5, Phase::Moved),
+            finger(4, -1000, 0, Phase::Cancelled),
+        ];
+
+        assert_eq!(centroid(frame[..1].iter()), Some(TouchPoint::new(10, 20)));
+        assert_eq!(centroid(frame[..2].iter()), Some(TouchPoint::new(20, -10)));
+        assert_eq!(centroid(frame.iter()), Some(TouchPoint::new(30, -5)));
+        assert_eq!(centroid([&frame[2], &frame[4]].into_iter()), None);
+
+        let far = [
+            finger(0, i32::MAX, i32::MIN, Phase::Moved),
+            finger(1, i32::MAX, i32::MIN, Phase::Moved),
+        ];
+        assert_eq!(
+            centroid(far.iter()),
+            Some(TouchPoint::new(i32::MAX, i32::MIN))
+        );
+    }
+}
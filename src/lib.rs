@@ -2,18 +2,52 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{
-    fmt::Debug,
-    ops::{Add, AddAssign, Sub, SubAssign},
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
 use fixed::{traits::ToFixed, types::U17F15};
 use fixed_macro::types::{I17F15, U17F15};
 
+pub mod builder;
+pub mod calibration;
+pub mod combined;
+pub mod diff;
+pub mod encoding;
+pub mod filter;
+#[cfg(feature = "heapless")]
+pub mod frame;
+pub mod gesture;
+#[cfg(feature = "hid")]
+pub mod hid;
+pub mod history;
+mod id_map;
+pub mod iter;
+#[cfg(feature = "alloc")]
+pub mod mock;
+pub mod null;
+pub mod pointer;
+pub mod relative;
+mod ring;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(test)]
+mod test_util;
+pub mod throttle;
+pub mod tracking;
 pub mod traits;
+pub mod transform;
+mod trig;
+pub mod validate;
 
 /// Represents a single touch point on the screen
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Touch {
     /// Unique ID for tracking this touch point across frames
     ///
@@ -42,10 +76,118 @@ impl Touch {
             tool,
         }
     }
+
+    /// Create a finger touch
+    #[must_use]
+    pub fn finger(id: u8, location: TouchPoint, phase: Phase) -> Self {
+        Touch::new(id, location, phase, Tool::Finger)
+    }
+
+    /// Create a virtual pointer touch with the given button and no wheel delta
+    ///
+    /// Devices with several pointers, such as a test harness synthesizing input, should
+    /// give each pointer its own ID, each with its own button.
+    #[must_use]
+    pub fn pointer(id: u8, location: TouchPoint, button: PointerButton, phase: Phase) -> Self {
+        let tool = Tool::Pointer {
+            button,
+            wheel_delta: None,
+        };
+        Touch::new(id, location, phase, tool)
+    }
+
+    /// Create a stylus touch with the given tip pressure in grams
+    ///
+    /// The barrel pressure, tilt, and azimuth are not reported. Use [`Touch::builder`] to
+    /// set them.
+    #[must_use]
+    pub fn stylus(id: u8, location: TouchPoint, pressure: Option<u16>, phase: Phase) -> Self {
+        let tool = Tool::Stylus {
+            pressure,
+            barrel_pressure: None,
+            tilt: None,
+            azimuth: None,
+        };
+        Touch::new(id, location, phase, tool)
+    }
+
+    /// Create a builder for a touch point
+    ///
+    /// See [`builder::TouchBuilder`] for defaults.
+    #[must_use]
+    pub fn builder() -> builder::TouchBuilder {
+        builder::TouchBuilder::new()
+    }
+
+    /// Returns `true` if the touches have the same location, phase, and tool
+    ///
+    /// The ID is ignored, which is useful in tests where IDs are assigned by a driver.
+    #[must_use]
+    pub fn eq_ignoring_id(&self, other: &Touch) -> bool {
+        self.location == other.location && self.phase == other.phase && self.tool == other.tool
+    }
+}
+
+/// An inactive finger touch with ID 0 at the origin
+///
+/// The phase is [`Phase::Ended`], which is terminal, so a default touch in an
+/// initialized array is never mistaken for a finger on the screen, and its ID is free
+/// for reuse. [`Phase::Started`] would instead report a new contact that never happened.
+impl Default for Touch {
+    fn default() -> Self {
+        Touch::new(0, TouchPoint::ORIGIN, Phase::Ended, Tool::Finger)
+    }
+}
+
+/// A touch paired with the time it was read, for timing-sensitive processing such as
+/// gesture recognition
+///
+/// Touch devices do not timestamp touches themselves, so whoever reads the device is
+/// responsible for stamping each touch as close to the read as possible.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimedTouch {
+    /// The touch
+    pub touch: Touch,
+    /// Time the touch was read, in milliseconds from an arbitrary epoch
+    ///
+    /// The timestamp may wrap around, so durations should be computed with
+    /// [`u32::wrapping_sub`].
+    pub timestamp_ms: u32,
+}
+
+impl TimedTouch {
+    /// Create a new timed touch
+    #[must_use]
+    pub fn new(touch: Touch, timestamp_ms: u32) -> Self {
+        Self {
+            touch,
+            timestamp_ms,
+        }
+    }
+}
+
+impl From<(Touch, u32)> for TimedTouch {
+    fn from((touch, timestamp_ms): (Touch, u32)) -> Self {
+        Self::new(touch, timestamp_ms)
+    }
+}
+
+impl From<TimedTouch> for Touch {
+    fn from(timed: TimedTouch) -> Self {
+        timed.touch
+    }
 }
 
 /// Phase of a touch interaction
+///
+/// New phases may be added in minor releases, so matches outside this crate need a
+/// wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum Phase {
     /// Touch just started
     Started,
@@ -58,10 +200,54 @@ pub enum Phase {
     /// Touch is hovering above the screen without contact, with an optional
     /// proximity (implementation-specific units)
     Hovering(Option<u16>),
+    /// Touch is still in contact but has not moved since the previous report
+    ///
+    /// Drivers are not required to report this phase, and many report a held touch as
+    /// [`Phase::Moved`] at an unchanged location or stop reporting it until it moves.
+    /// [`StationaryFilter`](filter::StationaryFilter) derives it from the former, but
+    /// neither can tell a resting touch apart from a driver that has stopped reporting.
+    Stationary,
+}
+
+impl Phase {
+    /// Returns `true` if the touch has ended or was cancelled
+    ///
+    /// The touch ID may be reused after a terminal phase.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Phase::Ended | Phase::Cancelled)
+    }
+
+    /// Returns `true` if the touch is in contact with the screen
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        matches!(self, Phase::Started | Phase::Moved | Phase::Stationary)
+    }
+
+    /// Returns `true` if the touch is hovering above the screen
+    #[must_use]
+    pub fn is_hovering(&self) -> bool {
+        matches!(self, Phase::Hovering(_))
+    }
+
+    /// Returns the hover proximity, if the touch is hovering and the device reports it
+    #[must_use]
+    pub fn proximity(&self) -> Option<u16> {
+        match self {
+            Phase::Hovering(proximity) => *proximity,
+            _ => None,
+        }
+    }
 }
 
 /// Tool/instrument used for touch interaction
+///
+/// New tools may be added in minor releases, so matches outside this crate need a
+/// wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum Tool {
     /// Finger or unknown tool
     Finger,
@@ -69,11 +255,23 @@ pub enum Tool {
     Pointer {
         /// The button pressed on the virtual pointer
         button: PointerButton,
+        /// Scroll wheel movement since the previous event, as `(horizontal, vertical)`
+        ///
+        /// Units are wheel detents (notches), not pixels. Positive values scroll toward
+        /// the right and bottom of the content, following the screen axes. This may be
+        /// reported while hovering, to express scrolling without a button pressed.
+        wheel_delta: Option<(i16, i16)>,
     },
     /// Passive or active stylus
     Stylus {
         /// Pressure, in grams
         pressure: Option<u16>,
+        /// Pressure on the barrel of the stylus, in grams, for styluses with a pressure
+        /// sensitive side grip or button
+        ///
+        /// This is independent of the tip `pressure`, and may be reported while the tip is
+        /// hovering.
+        barrel_pressure: Option<u16>,
         /// Tilt angle
         ///
         /// 0 degrees is a vector normal to the screen, and 90 degrees is parallel to the screen.
@@ -83,10 +281,154 @@ pub enum Tool {
         /// 0 degrees points up to the top of the screen in its default orientation.
         azimuth: Option<UnitAngle>,
     },
+    /// The eraser end of a stylus
+    ///
+    /// The fields have the same meaning as for [`Tool::Stylus`].
+    Eraser {
+        /// Pressure, in grams
+        pressure: Option<u16>,
+        /// Tilt angle
+        tilt: Option<UnitAngle>,
+        /// Azimuth angle
+        azimuth: Option<UnitAngle>,
+    },
+    /// Finger or unknown tool, with the size of the contact ellipse reported by the device
+    Contact {
+        /// Length of the major axis of the contact ellipse, in pixels
+        major_axis: u16,
+        /// Length of the minor axis of the contact ellipse, in pixels
+        minor_axis: u16,
+    },
+}
+
+impl Tool {
+    /// Returns `true` if the tool is a finger, with or without a reported contact size
+    #[must_use]
+    pub fn is_finger(&self) -> bool {
+        matches!(self, Tool::Finger | Tool::Contact { .. })
+    }
+
+    /// Returns `true` if the tool is a virtual pointer
+    #[must_use]
+    pub fn is_pointer(&self) -> bool {
+        matches!(self, Tool::Pointer { .. })
+    }
+
+    /// Returns `true` if the tool is a stylus
+    ///
+    /// This is `false` for the eraser end of a stylus, see [`Tool::is_eraser`].
+    #[must_use]
+    pub fn is_stylus(&self) -> bool {
+        matches!(self, Tool::Stylus { .. })
+    }
+
+    /// Returns `true` if the tool is the eraser end of a stylus
+    #[must_use]
+    pub fn is_eraser(&self) -> bool {
+        matches!(self, Tool::Eraser { .. })
+    }
+
+    /// Returns the `(horizontal, vertical)` scroll wheel delta in detents, if the tool is a
+    /// pointer that reports it
+    #[must_use]
+    pub fn wheel_delta(&self) -> Option<(i16, i16)> {
+        match self {
+            Tool::Pointer { wheel_delta, .. } => *wheel_delta,
+            _ => None,
+        }
+    }
+
+    /// Returns the major and minor axes of the contact ellipse in pixels, if reported
+    #[must_use]
+    pub fn contact_size(&self) -> Option<(u16, u16)> {
+        match self {
+            Tool::Contact {
+                major_axis,
+                minor_axis,
+            } => Some((*major_axis, *minor_axis)),
+            _ => None,
+        }
+    }
+
+    /// Returns the stylus pressure in grams, if the tool is a stylus or eraser that
+    /// reports it
+    #[must_use]
+    pub fn pressure(&self) -> Option<u16> {
+        match self {
+            Tool::Stylus { pressure, .. } | Tool::Eraser { pressure, .. } => *pressure,
+            _ => None,
+        }
+    }
+
+    /// Returns the stylus barrel pressure in grams, if the tool is a stylus that reports it
+    ///
+    /// This is independent of the tip pressure returned by [`Tool::pressure`].
+    #[must_use]
+    pub fn barrel_pressure(&self) -> Option<u16> {
+        match self {
+            Tool::Stylus {
+                barrel_pressure, ..
+            } => *barrel_pressure,
+            _ => None,
+        }
+    }
+
+    /// Returns the stylus pressure normalized to the range [0, 1]
+    ///
+    /// Pressures above `max_grams` are clamped to 1. Returns `None` if the tool does not
+    /// report pressure, or if `max_grams` is 0.
+    #[must_use]
+    pub fn normalized_pressure(&self, max_grams: u16) -> Option<f32> {
+        if max_grams == 0 {
+            return None;
+        }
+        self.pressure()
+            .map(|pressure| (f32::from(pressure) / f32::from(max_grams)).min(1.0))
+    }
+
+    /// Returns the stylus tilt, if the tool is a stylus or eraser that reports it
+    #[must_use]
+    pub fn tilt(&self) -> Option<UnitAngle> {
+        match self {
+            Tool::Stylus { tilt, .. } | Tool::Eraser { tilt, .. } => *tilt,
+            _ => None,
+        }
+    }
+
+    /// Returns the stylus azimuth, if the tool is a stylus or eraser that reports it
+    #[must_use]
+    pub fn azimuth(&self) -> Option<UnitAngle> {
+        match self {
+            Tool::Stylus { azimuth, .. } | Tool::Eraser { azimuth, .. } => *azimuth,
+            _ => None,
+        }
+    }
+
+    /// Returns the unit vector pointing from the stylus tip along the pen body
+    ///
+    /// The vector is `(x, y, z)` in screen axes, with x to the right, y down, and z out of
+    /// the screen, so a pen normal to the screen points along `(0, 0, 1)` and a pen
+    /// leaning toward the top of the screen has a negative y component. Azimuth increases
+    /// clockwise, so a pen at 90° azimuth leans toward the right. Returns `None`
+    /// unless the tool is a stylus or eraser reporting both tilt and azimuth.
+    #[must_use]
+    pub fn orientation_vector(&self) -> Option<(f32, f32, f32)> {
+        let to_f32 = |value: i32| fixed::types::I2F30::from_bits(value).to_num::<f32>();
+        let (tilt_sin, tilt_cos) = trig::sin_cos(self.tilt()?);
+        let (azimuth_sin, azimuth_cos) = trig::sin_cos(self.azimuth()?);
+        let (tilt_sin, tilt_cos) = (to_f32(tilt_sin), to_f32(tilt_cos));
+        Some((
+            tilt_sin * to_f32(azimuth_sin),
+            -tilt_sin * to_f32(azimuth_cos),
+            tilt_cos,
+        ))
+    }
 }
 
 /// The button state of a virtual pointer device
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum PointerButton {
     /// No button pressed, e.g., mouse hover state
     None,
@@ -98,13 +440,136 @@ pub enum PointerButton {
     Tertiary,
 }
 
+impl PointerButton {
+    /// Returns the button with the given index
+    ///
+    /// Indices follow the usual numbering of mouse buttons: 0 is no button,
+    /// 1 is [`PointerButton::Primary`], 2 is [`PointerButton::Secondary`], and 3 is
+    /// [`PointerButton::Tertiary`]. Returns `None` for any other index.
+    #[must_use]
+    pub const fn from_index(index: u8) -> Option<PointerButton> {
+        match index {
+            0 => Some(PointerButton::None),
+            1 => Some(PointerButton::Primary),
+            2 => Some(PointerButton::Secondary),
+            3 => Some(PointerButton::Tertiary),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the button
+    ///
+    /// See [`PointerButton::from_index`] for the mapping.
+    #[must_use]
+    pub const fn to_index(&self) -> u8 {
+        match self {
+            PointerButton::None => 0,
+            PointerButton::Primary => 1,
+            PointerButton::Secondary => 2,
+            PointerButton::Tertiary => 3,
+        }
+    }
+}
+
+/// A set of simultaneously held virtual pointer buttons
+///
+/// Unlike [`PointerButton`], this can represent multiple buttons held at once, e.g.
+/// primary and secondary during a gesture.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PointerButtons(u8);
+
+impl PointerButtons {
+    /// Primary mouse button, typically left
+    pub const PRIMARY: Self = PointerButtons(1 << 0);
+    /// Secondary mouse button, typically right
+    pub const SECONDARY: Self = PointerButtons(1 << 1);
+    /// Tertiary mouse button, typically middle or wheel
+    pub const TERTIARY: Self = PointerButtons(1 << 2);
+
+    /// Create an empty set with no buttons held
+    #[must_use]
+    pub const fn empty() -> Self {
+        PointerButtons(0)
+    }
+
+    /// Returns the raw bits of the set
+    #[must_use]
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if no buttons are held
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every button in `other` is held
+    #[must_use]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds the buttons in `other` to the set
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Removes the buttons in `other` from the set
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl core::ops::BitOr for PointerButtons {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        PointerButtons(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for PointerButtons {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+impl From<PointerButton> for PointerButtons {
+    fn from(button: PointerButton) -> Self {
+        match button {
+            PointerButton::None => PointerButtons::empty(),
+            PointerButton::Primary => PointerButtons::PRIMARY,
+            PointerButton::Secondary => PointerButtons::SECONDARY,
+            PointerButton::Tertiary => PointerButtons::TERTIARY,
+        }
+    }
+}
+
+/// 2π with 60 fractional bits, for exact fixed-point conversions to and from radians
+const TAU_Q60: i128 = 7_244_019_458_077_122_842;
+
 /// An angle in the range [0, 2π) radians
 ///
 /// The angle is stored as a [`fixed::types::U1F15`]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Angles are ordered linearly by their stored value in [0, 2π). This is not a circular
+/// ordering, so 359° sorts after 1° even though they are only 2° apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UnitAngle(fixed::types::U1F15);
 
 impl UnitAngle {
+    /// An angle of 0 radians
+    pub const ZERO: UnitAngle = UnitAngle::from_raw(0x0000);
+    /// An angle of π/2 radians (90°)
+    pub const HALF_PI: UnitAngle = UnitAngle::from_raw(0x4000);
+    /// An angle of π radians (180°)
+    pub const PI: UnitAngle = UnitAngle::from_raw(0x8000);
+    /// An angle of 3π/2 radians (270°)
+    pub const THREE_HALF_PI: UnitAngle = UnitAngle::from_raw(0xC000);
+
     /// Create a new angle from an angle in π * radians.
     ///
     /// Angles outside the range [0, 2) are wrapped.
@@ -136,6 +601,55 @@ impl UnitAngle {
         UnitAngle(radians.wrapping_to_fixed())
     }
 
+    /// Create a new angle from an angle in degrees, clamped between `min` and `max` degrees
+    ///
+    /// Unlike [`UnitAngle::from_degrees`], the value is clamped before it is wrapped into
+    /// [0, 360), so 120 clamped to [0, 90] is 90° and -30 is 0°. Clamping a circular
+    /// quantity is only meaningful within a sub-turn range: a `max` of 360 or more still
+    /// wraps, so 360 gives 0°. If `min` is greater than `max`, the result is `max`.
+    #[must_use]
+    pub fn from_degrees_clamped(value: impl ToFixed, min: impl ToFixed, max: impl ToFixed) -> Self {
+        let clamp = |value: fixed::types::I17F15| {
+            value
+                .max(min.saturating_to_fixed())
+                .min(max.saturating_to_fixed())
+        };
+        UnitAngle::from_degrees(clamp(value.saturating_to_fixed()))
+    }
+
+    /// Create a new angle from an angle in radians, clamped between `min` and `max` radians
+    ///
+    /// The value is clamped before it is wrapped, as for
+    /// [`UnitAngle::from_degrees_clamped`]. Negative bounds are allowed.
+    #[must_use]
+    pub fn from_radians_clamped(value: impl ToFixed, min: impl ToFixed, max: impl ToFixed) -> Self {
+        let value = value
+            .saturating_to_fixed::<fixed::types::I17F15>()
+            .max(min.saturating_to_fixed())
+            .min(max.saturating_to_fixed());
+        let pi_radians = value / I17F15!(3.14159265359);
+        UnitAngle(pi_radians.wrapping_to_fixed())
+    }
+
+    /// Create a new angle from the raw bits of the underlying [`fixed::types::U1F15`]
+    ///
+    /// The full range of `u16` maps onto [0, 2π), so `0x4000` is π/2 radians, `0x8000` is
+    /// π radians, and `0xC000` is 3π/2 radians. Unlike the other constructors, this can be
+    /// used in `const` contexts.
+    #[must_use]
+    pub const fn from_raw(bits: u16) -> Self {
+        UnitAngle(fixed::types::U1F15::from_bits(bits))
+    }
+
+    /// Returns the raw bits of the underlying [`fixed::types::U1F15`]
+    ///
+    /// See [`UnitAngle::from_raw`] for the mapping of raw values to angles.
+    #[must_use]
+    #[inline]
+    pub const fn as_raw(&self) -> u16 {
+        self.0.to_bits()
+    }
+
     /// Returns the angle in π radians, in the range [0, 2)
     ///
     /// This method does not result in loss of precision from the original value.
@@ -145,6 +659,49 @@ impl UnitAngle {
         self.0
     }
 
+    /// Create a new angle from a signed angle in π radians, in the range [-1, 1)
+    ///
+    /// Negative angles map onto [π, 2π). This is the exact inverse of
+    /// [`UnitAngle::as_signed_pi_radians`].
+    #[must_use]
+    pub const fn from_signed_pi_radians(value: fixed::types::I1F15) -> Self {
+        UnitAngle::from_raw(value.to_bits().cast_unsigned())
+    }
+
+    /// Returns the angle in π radians, in the signed range [-1, 1)
+    ///
+    /// Angles in [π, 2π) map onto [-π, 0). This method does not result in loss of
+    /// precision from the original value.
+    #[must_use]
+    pub const fn as_signed_pi_radians(&self) -> fixed::types::I1F15 {
+        fixed::types::I1F15::from_bits(self.as_raw().cast_signed())
+    }
+
+    /// Create a new angle from an angle in radians as a [`fixed::types::I16F16`]
+    ///
+    /// Angles outside the range [0, 2π) are wrapped. The conversion is done entirely in
+    /// fixed-point, and rounds to the nearest representable angle, since the resolution of
+    /// [`UnitAngle`] (about 9.6 × 10⁻⁵ radians) is coarser than that of `I16F16`.
+    #[must_use]
+    pub fn from_i16f16_radians(value: fixed::types::I16F16) -> Self {
+        let numerator = i128::from(value.to_bits()) << 60;
+        let raw = (numerator + TAU_Q60 / 2).div_euclid(TAU_Q60);
+        // Wrap into a single turn; the remainder always fits in 16 bits
+        UnitAngle::from_raw(u16::try_from(raw.rem_euclid(0x10000)).unwrap_or(0))
+    }
+
+    /// Returns the angle in radians as a [`fixed::types::I16F16`], in the range [0, 2π)
+    ///
+    /// The conversion is done entirely in fixed-point, and rounds to the nearest multiple
+    /// of 2⁻¹⁶ radians. Converting back with [`UnitAngle::from_i16f16_radians`] gives the
+    /// original angle.
+    #[must_use]
+    pub fn as_i16f16_radians(&self) -> fixed::types::I16F16 {
+        let bits = (i128::from(self.as_raw()) * TAU_Q60 + (1 << 59)) >> 60;
+        // At most 2π · 2¹⁶, which is well within the range of i32
+        fixed::types::I16F16::from_bits(i32::try_from(bits).unwrap_or(i32::MAX))
+    }
+
     #[must_use]
     #[inline]
     pub fn as_radians_f32(&self) -> f32 {
@@ -156,74 +713,719 @@ impl UnitAngle {
     pub fn as_degrees_f32(&self) -> f32 {
         (self.0.to_fixed::<U17F15>() * U17F15!(180.0)).to_num::<f32>()
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct TouchPoint {
-    pub x: i32,
-    pub y: i32,
-}
+    /// Returns the shortest signed rotation from this angle to `other`
+    ///
+    /// The result is in units of 2⁻¹⁵ π radians (the resolution of the underlying
+    /// [`fixed::types::U1F15`]) in the range (-32768, 32768], corresponding to
+    /// (-180°, 180°]. Positive values indicate rotation in the direction of increasing
+    /// angle, so the difference from 350° to 10° is +20°, not -340°.
+    ///
+    /// ```
+    /// # use embedded_touch::UnitAngle;
+    /// let delta = UnitAngle::from_degrees(350).signed_difference(&UnitAngle::from_degrees(10));
+    /// let degrees = delta as f32 * 180.0 / 32768.0;
+    /// assert!((degrees - 20.0).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn signed_difference(&self, other: &UnitAngle) -> i32 {
+        let delta = i32::from(other.0.to_bits().wrapping_sub(self.0.to_bits()));
+        // Shift the half-open range so that exactly half a turn maps to +π
+        ((delta + 0x7FFF) & 0xFFFF) - 0x7FFF
+    }
 
-impl TouchPoint {
-    /// Create a new touch point
+    /// Returns `true` if the shortest rotation between the angles is at most `tolerance`
+    ///
+    /// This wraps around correctly, so angles just either side of 0 compare as close.
     #[must_use]
-    pub fn new(x: impl Into<i32>, y: impl Into<i32>) -> Self {
-        Self {
-            x: x.into(),
-            y: y.into(),
+    pub fn approx_eq(&self, other: &UnitAngle, tolerance: UnitAngle) -> bool {
+        self.signed_difference(other).unsigned_abs() <= u32::from(tolerance.as_raw())
+    }
+
+    /// Multiplies the angle by `factor`, wrapping within [0, 2π)
+    ///
+    /// Products beyond a full turn wrap around, so 90° scaled by 5 is 90°, and negative
+    /// factors rotate backwards from 0, so 90° scaled by -1 is 270°. The result is rounded
+    /// to the nearest representable angle. Factors outside the range of [`i32`] saturate,
+    /// and a NaN factor gives 0.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn scale(&self, factor: f32) -> UnitAngle {
+        if factor.is_nan() {
+            return UnitAngle::ZERO;
         }
+        let factor = fixed::types::I32F32::saturating_from_num(factor);
+        let product = i128::from(self.as_raw()) * i128::from(factor.to_bits());
+        let rounded = (product + (1 << 31)) >> 32;
+        // Truncating to the low 16 bits wraps the product into a single turn
+        UnitAngle::from_raw(rounded as u16)
+    }
+
+    /// Multiplies the angle by `n`, wrapping within [0, 2π)
+    ///
+    /// This is exact. Products beyond a full turn wrap around, so 90° scaled by 5 is 90°.
+    #[must_use]
+    pub const fn scale_by(&self, n: u16) -> UnitAngle {
+        UnitAngle::from_raw(self.as_raw().wrapping_mul(n))
+    }
+
+    /// Clamps the angle between `min` and `max`
+    ///
+    /// Angles are compared by their value in [0, 2π), as for [`Ord`]. Clamping a circular
+    /// quantity is only meaningful within a sub-turn range, and ranges that cross 0 are
+    /// not supported: clamping to [350°, 10°] does not keep 355°. If `min` is greater
+    /// than `max`, the result is `max`, where [`Ord::clamp`] would panic.
+    ///
+    /// This takes `self` by value so that it is called in preference to [`Ord::clamp`].
+    #[must_use]
+    pub fn clamp(self, min: UnitAngle, max: UnitAngle) -> UnitAngle {
+        self.max(min).min(max)
+    }
+
+    /// Returns the sine and cosine of the angle
+    ///
+    /// This uses fixed-point CORDIC and needs no FPU. Results are within 2⁻¹⁵ of the true
+    /// values, except that 1 is not representable and saturates to [`I1F15::MAX`].
+    ///
+    /// [`I1F15::MAX`]: fixed::types::I1F15::MAX
+    #[must_use]
+    pub fn sin_cos(&self) -> (fixed::types::I1F15, fixed::types::I1F15) {
+        let (sin, cos) = trig::sin_cos(*self);
+        let to_fixed = |value: i32| {
+            // Only exactly +1 falls outside the range of i16
+            let bits = i16::try_from((value + (1 << 14)) >> 15).unwrap_or(i16::MAX);
+            fixed::types::I1F15::from_bits(bits)
+        };
+        (to_fixed(sin), to_fixed(cos))
     }
 }
 
-impl Add for TouchPoint {
+/// Adds two angles, wrapping within [0, 2π)
+impl Add for UnitAngle {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        TouchPoint {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+        UnitAngle(self.0.wrapping_add(rhs.0))
     }
 }
 
-impl AddAssign for TouchPoint {
+impl AddAssign for UnitAngle {
     fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+        *self = *self + rhs;
     }
 }
 
-impl Sub for TouchPoint {
+/// Subtracts two angles, wrapping within [0, 2π)
+impl Sub for UnitAngle {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        TouchPoint {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+        UnitAngle(self.0.wrapping_sub(rhs.0))
     }
 }
 
-impl SubAssign for TouchPoint {
+impl SubAssign for UnitAngle {
     fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
+        *self = *self - rhs;
     }
 }
 
-impl core::ops::Neg for TouchPoint {
+/// Reflects the angle across 0, returning 2π - angle wrapped within [0, 2π)
+///
+/// This is exact, and 0 and π map onto themselves.
+impl core::ops::Neg for UnitAngle {
     type Output = Self;
-    fn neg(self) -> Self {
-        Self {
-            x: -self.x,
-            y: -self.y,
-        }
+
+    fn neg(self) -> Self::Output {
+        UnitAngle(self.0.wrapping_neg())
     }
 }
 
-#[cfg(test)]
+impl Display for UnitAngle {
+    /// Formats the angle in degrees, e.g. `45.0°`
+    ///
+    /// Defaults to one decimal place, which can be overridden with the precision flag
+    /// (`{:.2}` prints two decimal places).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(1);
+        write!(f, "{:.*}°", precision, self.as_degrees_f32())
+    }
+}
+
+/// Formatted in degrees, matching the [`Display`] implementation
+#[cfg(feature = "defmt")]
+impl defmt::Format for UnitAngle {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=f32}°", self.as_degrees_f32());
+    }
+}
+
+/// Serialized as the raw bits of the inner [`fixed::types::U1F15`], so round trips are lossless
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnitAngle {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0.to_bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnitAngle {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u16::deserialize(deserializer)?;
+        Ok(UnitAngle(fixed::types::U1F15::from_bits(bits)))
+    }
+}
+
+/// A 2D point with coordinates of type `T`
+///
+/// Touch locations use integer pixels through the [`TouchPoint`] alias. Other coordinate
+/// types such as [`f32`] or [`fixed::types::U17F15`] can be used where sub-pixel
+/// precision matters.
+///
+/// The default point is the origin, with both coordinates at their default of zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A touch location in integer pixels
+pub type TouchPoint = Point<i32>;
+
+impl TouchPoint {
+    /// The point at (0, 0)
+    pub const ORIGIN: TouchPoint = TouchPoint { x: 0, y: 0 };
+
+    /// Create a new touch point
+    #[must_use]
+    pub fn new(x: impl Into<i32>, y: impl Into<i32>) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    /// Create a new touch point in const context
+    ///
+    /// Unlike [`TouchPoint::new`], this takes [`i32`] directly, so it can be used to
+    /// build `const` tables of points.
+    #[must_use]
+    pub const fn from_xy(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns `true` if both components are 0
+    #[must_use]
+    pub const fn is_origin(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
+    /// Returns the dot product of this point and another, treated as vectors
+    ///
+    /// Products are computed in [`i64`]. The only sum that does not fit saturates to
+    /// [`i64::MAX`], when every component is [`i32::MIN`].
+    #[must_use]
+    pub fn dot(&self, other: &TouchPoint) -> i64 {
+        (i64::from(self.x) * i64::from(other.x))
+            .saturating_add(i64::from(self.y) * i64::from(other.y))
+    }
+
+    /// Returns the z component of the cross product of this point and another, treated as
+    /// vectors
+    ///
+    /// The result is positive if `other` is clockwise from this vector on a screen where y
+    /// points down, negative if counterclockwise, and 0 if they are parallel. This is
+    /// computed in [`i64`] and never overflows.
+    #[must_use]
+    pub fn cross(&self, other: &TouchPoint) -> i64 {
+        i64::from(self.x) * i64::from(other.y) - i64::from(self.y) * i64::from(other.x)
+    }
+
+    /// Returns the angle of this point as a vector from the origin
+    ///
+    /// The angle is measured from the +x axis toward the +y axis. On a screen where y
+    /// points down, this is clockwise, so (0, 1) pointing down is 90° and (0, -1) pointing
+    /// up is 270°. This is computed in fixed-point and needs no FPU. The origin has no
+    /// direction and returns 0.
+    #[must_use]
+    pub fn angle(&self) -> UnitAngle {
+        trig::atan2(i64::from(self.y), i64::from(self.x)).unwrap_or(UnitAngle::ZERO)
+    }
+
+    /// Returns the Euclidean distance to another point
+    ///
+    /// The square root is computed in fixed-point, so this does not require an FPU until
+    /// the final conversion to [`f32`].
+    #[must_use]
+    pub fn distance(&self, other: &TouchPoint) -> f32 {
+        let dx = u128::from(self.x.abs_diff(other.x));
+        let dy = u128::from(self.y.abs_diff(other.y));
+        fixed::types::U80F48::from_num(dx * dx + dy * dy)
+            .sqrt()
+            .to_num::<f32>()
+    }
+
+    /// Returns the squared Euclidean distance to another point
+    ///
+    /// This avoids the square root of [`TouchPoint::distance`] for threshold comparisons.
+    /// Intermediates are widened to [`i64`], so this does not overflow for any pair of
+    /// points within [`i32::MAX`] of each other on both axes. Larger separations
+    /// saturate at [`i64::MAX`].
+    #[must_use]
+    pub fn distance_squared(&self, other: &TouchPoint) -> i64 {
+        let dx = i64::from(other.x) - i64::from(self.x);
+        let dy = i64::from(other.y) - i64::from(self.y);
+        dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+    }
+
+    /// Returns the length of this point as a vector from the origin
+    ///
+    /// Like [`TouchPoint::distance`], the square root is computed in fixed-point.
+    #[must_use]
+    pub fn magnitude(&self) -> f32 {
+        self.distance(&TouchPoint::ORIGIN)
+    }
+
+    /// Returns the squared length of this point as a vector from the origin
+    ///
+    /// This avoids the square root of [`TouchPoint::magnitude`] for threshold comparisons.
+    /// Squares are computed in [`i64`], so every point fits except when both components
+    /// are [`i32::MIN`], which saturates at [`i64::MAX`].
+    #[must_use]
+    pub fn magnitude_squared(&self) -> i64 {
+        self.distance_squared(&TouchPoint::ORIGIN)
+    }
+
+    /// Returns the Manhattan (L1) distance to another point, `|dx| + |dy|`
+    ///
+    /// This is cheaper than [`TouchPoint::distance`] on targets without an FPU, and does
+    /// not overflow for any pair of points.
+    #[must_use]
+    pub fn manhattan_distance(&self, other: &TouchPoint) -> u64 {
+        u64::from(self.x.abs_diff(other.x)) + u64::from(self.y.abs_diff(other.y))
+    }
+
+    /// Returns the Chebyshev (L∞) distance to another point, `max(|dx|, |dy|)`
+    ///
+    /// This is useful for square hit-testing regions.
+    #[must_use]
+    pub fn l_infinity_distance(&self, other: &TouchPoint) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Returns the point halfway between this point and another
+    ///
+    /// This never overflows, and each component is rounded toward zero as if computed
+    /// with [`i64`] intermediates.
+    #[must_use]
+    pub fn midpoint(&self, other: &TouchPoint) -> TouchPoint {
+        TouchPoint {
+            x: self.x.midpoint(other.x),
+            y: self.y.midpoint(other.y),
+        }
+    }
+
+    /// Adds another point component-wise, saturating at the bounds of [`i32`]
+    ///
+    /// Unlike the [`Add`] impl, which panics on overflow in debug builds and wraps in
+    /// release builds, each component is clamped to [`i32::MIN`] or [`i32::MAX`].
+    #[must_use]
+    pub const fn saturating_add(&self, rhs: TouchPoint) -> TouchPoint {
+        TouchPoint {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Subtracts another point component-wise, saturating at the bounds of [`i32`]
+    ///
+    /// Unlike the [`Sub`] impl, which panics on overflow in debug builds and wraps in
+    /// release builds, each component is clamped to [`i32::MIN`] or [`i32::MAX`].
+    #[must_use]
+    pub const fn saturating_sub(&self, rhs: TouchPoint) -> TouchPoint {
+        TouchPoint {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Adds another point component-wise, returning `None` if either component overflows
+    #[must_use]
+    pub const fn checked_add(&self, rhs: TouchPoint) -> Option<TouchPoint> {
+        match (self.x.checked_add(rhs.x), self.y.checked_add(rhs.y)) {
+            (Some(x), Some(y)) => Some(TouchPoint { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Subtracts another point component-wise, returning `None` if either component
+    /// overflows
+    #[must_use]
+    pub const fn checked_sub(&self, rhs: TouchPoint) -> Option<TouchPoint> {
+        match (self.x.checked_sub(rhs.x), self.y.checked_sub(rhs.y)) {
+            (Some(x), Some(y)) => Some(TouchPoint { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Linearly interpolates between this point (`t = 0.0`) and another (`t = 1.0`)
+    ///
+    /// `t` is clamped to the range [0, 1]. The offset from this point is truncated, so
+    /// each component of the result is rounded toward `self`.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn lerp(&self, other: &TouchPoint, t: f32) -> TouchPoint {
+        let t = f64::from(t.clamp(0.0, 1.0));
+        let component = |from: i32, to: i32| {
+            let offset = ((f64::from(to) - f64::from(from)) * t) as i64;
+            // The result lies between `from` and `to`, so it always fits in an i32
+            (i64::from(from) + offset) as i32
+        };
+        TouchPoint {
+            x: component(self.x, other.x),
+            y: component(self.y, other.y),
+        }
+    }
+
+    /// Returns the axis with the larger magnitude, treating this point as a delta
+    ///
+    /// Ties, including the zero vector, are [`Axis::Horizontal`].
+    #[must_use]
+    pub fn dominant_axis(&self) -> Axis {
+        if self.x.unsigned_abs() >= self.y.unsigned_abs() {
+            Axis::Horizontal
+        } else {
+            Axis::Vertical
+        }
+    }
+
+    /// Returns the direction along the dominant axis, treating this point as a delta
+    ///
+    /// Ties are broken as in [`TouchPoint::dominant_axis`], and the zero vector is
+    /// [`Direction::Right`].
+    #[must_use]
+    pub fn dominant_direction(&self) -> Direction {
+        match self.dominant_axis() {
+            Axis::Horizontal if self.x < 0 => Direction::Left,
+            Axis::Horizontal => Direction::Right,
+            Axis::Vertical if self.y < 0 => Direction::Up,
+            Axis::Vertical => Direction::Down,
+        }
+    }
+
+    /// Clamps this point onto a `width` × `height` pixel panel
+    ///
+    /// The panel is half-open: x is clamped to `0..width` and y to `0..height`, so the
+    /// result is always a valid pixel index for a non-empty panel. An empty dimension
+    /// clamps to 0.
+    #[must_use]
+    pub fn clamp_to(&self, width: u32, height: u32) -> TouchPoint {
+        Rect::new(TouchPoint::ORIGIN, width, height).clamp(*self)
+    }
+
+    /// Rounds each component to the nearest multiple of `step`
+    ///
+    /// Components exactly halfway between two multiples are rounded up, toward positive
+    /// infinity, so with a step of 10, 5 rounds to 10 and -5 rounds to 0. A step of 0
+    /// returns the point unchanged. Results beyond the range of [`i32`] saturate.
+    #[must_use]
+    pub fn snap_to_grid(&self, step: u32) -> TouchPoint {
+        if step == 0 {
+            return *self;
+        }
+        let step = i64::from(step);
+        let component = |value: i32| {
+            let snapped = (i64::from(value) + step / 2).div_euclid(step) * step;
+            i32::try_from(snapped).unwrap_or(if snapped < 0 { i32::MIN } else { i32::MAX })
+        };
+        TouchPoint {
+            x: component(self.x),
+            y: component(self.y),
+        }
+    }
+
+    /// Reflects this point horizontally on a panel `width` pixels wide
+    ///
+    /// The result has x at `width - 1 - x`. Pixel columns are the half-open range
+    /// `0..width`, so the last column is `width - 1`, and reflecting maps column 0 to
+    /// it and vice versa. Results beyond the range of [`i32`] saturate.
+    #[must_use]
+    pub fn flip_x(&self, width: u32) -> TouchPoint {
+        TouchPoint {
+            x: reflect(self.x, width),
+            y: self.y,
+        }
+    }
+
+    /// Reflects this point vertically on a panel `height` pixels tall
+    ///
+    /// The result has y at `height - 1 - y`, for the same reason as
+    /// [`TouchPoint::flip_x`].
+    #[must_use]
+    pub fn flip_y(&self, height: u32) -> TouchPoint {
+        TouchPoint {
+            x: self.x,
+            y: reflect(self.y, height),
+        }
+    }
+
+    /// Returns `true` if both components differ by at most `tolerance`
+    ///
+    /// A negative tolerance never matches.
+    #[must_use]
+    pub fn approx_eq(&self, other: &TouchPoint, tolerance: i32) -> bool {
+        u32::try_from(tolerance).is_ok_and(|tolerance| self.l_infinity_distance(other) <= tolerance)
+    }
+
+    /// Rotates this point by `angle` about `center`
+    ///
+    /// Positive angles rotate from the +x axis toward the +y axis, which is clockwise on
+    /// a screen with y pointing down. The rotation uses fixed-point sine and cosine, and
+    /// each component of the result is rounded to the nearest pixel, with halves rounded
+    /// up. Results beyond the range of [`i32`] saturate.
+    #[must_use]
+    pub fn rotate_around(&self, center: TouchPoint, angle: &UnitAngle) -> TouchPoint {
+        let (sin, cos) = trig::sin_cos(*angle);
+        let (sin, cos) = (i128::from(sin), i128::from(cos));
+        let dx = i128::from(self.x) - i128::from(center.x);
+        let dy = i128::from(self.y) - i128::from(center.y);
+        let component = |rotated: i128, center: i32| {
+            let offset = (rotated + (1 << 29)) >> 30;
+            let value = i128::from(center) + offset;
+            i32::try_from(value).unwrap_or(if value < 0 { i32::MIN } else { i32::MAX })
+        };
+        TouchPoint {
+            x: component(dx * cos - dy * sin, center.x),
+            y: component(dx * sin + dy * cos, center.y),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+/// Reflects a coordinate across the middle of an axis of `size` pixels
+fn reflect(value: i32, size: u32) -> i32 {
+    let reflected = i64::from(size) - 1 - i64::from(value);
+    i32::try_from(reflected).unwrap_or(if reflected < 0 { i32::MIN } else { i32::MAX })
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    fn from(point: Point<T>) -> Self {
+        [point.x, point.y]
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl From<embedded_graphics_core::geometry::Point> for TouchPoint {
+    fn from(point: embedded_graphics_core::geometry::Point) -> Self {
+        TouchPoint {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl From<TouchPoint> for embedded_graphics_core::geometry::Point {
+    fn from(point: TouchPoint) -> Self {
+        embedded_graphics_core::geometry::Point::new(point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: core::ops::Neg<Output = T>> core::ops::Neg for Point<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+/// Divides both components by a scalar
+///
+/// For integer coordinates, each component is rounded toward zero like Rust integer
+/// division.
+impl<T: Div<Output = T> + Copy> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Point {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+/// Divides both components by a scalar
+///
+/// For integer coordinates, each component is rounded toward zero like Rust integer
+/// division.
+impl<T: DivAssign + Copy> DivAssign<T> for Point<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+/// A screen axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Axis {
+    /// The x axis
+    Horizontal,
+    /// The y axis
+    Vertical,
+}
+
+/// A screen direction, with up pointing toward the top of the screen (negative y)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    /// Toward the top of the screen
+    Up,
+    /// Toward the bottom of the screen
+    Down,
+    /// Toward the left of the screen
+    Left,
+    /// Toward the right of the screen
+    Right,
+}
+
+/// An axis-aligned rectangle in screen pixels, for hit-testing touches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rect {
+    /// Top-left corner of the rectangle
+    pub origin: TouchPoint,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+}
+
+impl Rect {
+    /// Create a new rectangle
+    #[must_use]
+    pub fn new(origin: TouchPoint, width: u32, height: u32) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// Returns `true` if the point lies inside the rectangle
+    ///
+    /// The rectangle is half-open: it includes the top and left edges at `origin`, but
+    /// not the bottom and right edges at `origin + size`. Adjacent rectangles therefore
+    /// never both contain the same point, and an empty rectangle contains nothing.
+    #[must_use]
+    pub fn contains(&self, point: TouchPoint) -> bool {
+        let dx = i64::from(point.x) - i64::from(self.origin.x);
+        let dy = i64::from(point.y) - i64::from(self.origin.y);
+        (0..i64::from(self.width)).contains(&dx) && (0..i64::from(self.height)).contains(&dy)
+    }
+
+    /// Returns the point inside the rectangle nearest to `point`
+    ///
+    /// Each component is clamped to the last pixel inside the rectangle. An empty
+    /// rectangle clamps everything to its origin.
+    #[must_use]
+    pub fn clamp(&self, point: TouchPoint) -> TouchPoint {
+        let component = |value: i32, origin: i32, size: u32| {
+            let last = i64::from(origin) + i64::from(size.saturating_sub(1));
+            let last = i32::try_from(last).unwrap_or(i32::MAX);
+            value.clamp(origin, last)
+        };
+        TouchPoint {
+            x: component(point.x, self.origin.x, self.width),
+            y: component(point.y, self.origin.y, self.height),
+        }
+    }
+}
+
+#[cfg(test)]
 mod tests {
+    extern crate std;
+
     use core::f32;
+    use std::format;
 
     use super::*;
 
@@ -265,4 +1467,924 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn display_angle_in_degrees() {
+        assert_eq!(format!("{}", UnitAngle::from_degrees(90)), "90.0°");
+        assert_eq!(format!("{:.2}", UnitAngle::from_degrees(45)), "45.00°");
+        assert_eq!(format!("{:.0}", UnitAngle::from_degrees(180)), "180°");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_stylus_touch() {
+        let touch = Touch::new(
+            3,
+            TouchPoint::new(120, -45),
+            Phase::Moved,
+            Tool::Stylus {
+                pressure: Some(250),
+                barrel_pressure: None,
+                tilt: Some(UnitAngle::from_degrees(30)),
+                azimuth: Some(UnitAngle::from_pi_radians(fixed::types::U1F15::from_bits(
+                    0x1234,
+                ))),
+            },
+        );
+
+        let json = serde_json::to_string(&touch).unwrap();
+        let decoded: Touch = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, touch);
+    }
+
+    #[test]
+    fn distance_3_4_5() {
+        let a = TouchPoint::new(1, 2);
+        let b = TouchPoint::new(4, 6);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert!((a.distance(&b) - 5.0).abs() < 0.00001);
+        assert!((b.distance(&a) - 5.0).abs() < 0.00001);
+    }
+
+    #[test]
+    fn distance_squared_does_not_overflow_i32() {
+        let a = TouchPoint::new(0, 0);
+        let b = TouchPoint::new(i32::MAX, i32::MAX);
+        let expected = 2 * i64::from(i32::MAX) * i64::from(i32::MAX);
+        assert_eq!(a.distance_squared(&b), expected);
+
+        let c = TouchPoint::new(i32::MIN, i32::MIN);
+        assert_eq!(c.distance_squared(&b), i64::MAX);
+    }
+
+    #[test]
+    fn const_points() {
+        const CORNERS: [TouchPoint; 4] = [
+            TouchPoint::from_xy(0, 0),
+            TouchPoint::from_xy(799, 0),
+            TouchPoint::from_xy(799, 479),
+            TouchPoint::from_xy(0, 479),
+        ];
+        assert_eq!(CORNERS[2], TouchPoint::new(799, 479));
+        let perimeter: u64 = CORNERS
+            .iter()
+            .zip(CORNERS.iter().cycle().skip(1))
+            .map(|(a, b)| a.manhattan_distance(b))
+            .sum();
+        assert_eq!(perimeter, 2 * (799 + 479));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let point = TouchPoint::new(14, -16);
+        assert_eq!(point.snap_to_grid(10), TouchPoint::new(10, -20));
+        assert_eq!(
+            TouchPoint::new(17, -13).snap_to_grid(10),
+            TouchPoint::new(20, -10)
+        );
+
+        // Halves round toward positive infinity
+        assert_eq!(
+            TouchPoint::new(5, -5).snap_to_grid(10),
+            TouchPoint::new(10, 0)
+        );
+        assert_eq!(
+            TouchPoint::new(15, -15).snap_to_grid(10),
+            TouchPoint::new(20, -10)
+        );
+        // Odd steps have no exact half
+        assert_eq!(TouchPoint::new(1, 2).snap_to_grid(3), TouchPoint::new(0, 3));
+
+        assert_eq!(point.snap_to_grid(0), point);
+        assert_eq!(point.snap_to_grid(1), point);
+        assert_eq!(
+            TouchPoint::new(i32::MAX, i32::MIN).snap_to_grid(10),
+            TouchPoint::new(i32::MAX, i32::MIN)
+        );
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let near_max = TouchPoint::new(i32::MAX - 5, 100);
+        assert_eq!(
+            near_max.saturating_add(TouchPoint::new(10, -50)),
+            TouchPoint::new(i32::MAX, 50)
+        );
+        assert_eq!(
+            TouchPoint::new(i32::MIN + 1, -3).saturating_sub(TouchPoint::new(2, i32::MAX)),
+            TouchPoint::new(i32::MIN, i32::MIN)
+        );
+        assert_eq!(
+            near_max.saturating_sub(TouchPoint::new(-5, 1)),
+            TouchPoint::new(i32::MAX, 99)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let point = TouchPoint::new(10, -20);
+        assert_eq!(
+            point.checked_add(TouchPoint::new(5, 5)),
+            Some(TouchPoint::new(15, -15))
+        );
+        assert_eq!(
+            point.checked_sub(TouchPoint::new(5, 5)),
+            Some(TouchPoint::new(5, -25))
+        );
+        assert_eq!(point.checked_add(TouchPoint::new(i32::MAX, 0)), None);
+        assert_eq!(point.checked_sub(TouchPoint::new(0, i32::MAX)), None);
+    }
+
+    #[test]
+    fn flip() {
+        let (width, height) = (240, 320);
+        assert_eq!(TouchPoint::ORIGIN.flip_x(width), TouchPoint::new(239, 0));
+        assert_eq!(TouchPoint::ORIGIN.flip_y(height), TouchPoint::new(0, 319));
+        let corner = TouchPoint::new(239, 319);
+        assert_eq!(corner.flip_x(width).flip_y(height), TouchPoint::ORIGIN);
+
+        // The center column of an even-width panel has no single pixel, so the pixels
+        // either side of it swap
+        assert_eq!(
+            TouchPoint::new(120, 160).flip_x(width),
+            TouchPoint::new(119, 160)
+        );
+        assert_eq!(
+            TouchPoint::new(119, 159).flip_y(height),
+            TouchPoint::new(119, 160)
+        );
+
+        assert_eq!(TouchPoint::new(i32::MIN, 0).flip_x(width).x, i32::MAX);
+    }
+
+    #[test]
+    fn origin() {
+        assert_eq!(TouchPoint::ORIGIN, TouchPoint::new(0, 0));
+        assert!(TouchPoint::ORIGIN.is_origin());
+        assert!(!TouchPoint::new(0, 1).is_origin());
+        assert!(!TouchPoint::new(-1, 0).is_origin());
+    }
+
+    #[test]
+    fn dot_and_cross_products() {
+        let right = TouchPoint::new(10, 0);
+        let down = TouchPoint::new(0, 5);
+        assert_eq!(right.dot(&down), 0);
+        assert_eq!(right.dot(&TouchPoint::new(3, 7)), 30);
+        assert_eq!(right.dot(&-right), -100);
+
+        // Right to down is clockwise on screen
+        assert_eq!(right.cross(&down), 50);
+        assert_eq!(down.cross(&right), -50);
+        assert_eq!(right.cross(&(right * 3)), 0);
+
+        let min = TouchPoint::new(i32::MIN, i32::MIN);
+        assert_eq!(min.dot(&min), i64::MAX);
+        let max = TouchPoint::new(i32::MAX, i32::MAX);
+        assert_eq!(
+            min.cross(&TouchPoint::new(i32::MAX, i32::MIN)),
+            i64::MAX - (1 << 31) + 1
+        );
+        assert_eq!(max.cross(&max), 0);
+    }
+
+    #[test]
+    fn vector_angle() {
+        let degrees = |x: i32, y: i32| TouchPoint::new(x, y).angle().as_degrees_f32();
+        assert!(degrees(10, 0).abs() < 0.01);
+        assert!((degrees(0, 10) - 90.0).abs() < 0.01);
+        assert!((degrees(-10, 0) - 180.0).abs() < 0.01);
+        assert!((degrees(0, -10) - 270.0).abs() < 0.01);
+        assert!((degrees(7, 7) - 45.0).abs() < 0.01);
+        assert!((degrees(i32::MIN, i32::MIN) - 225.0).abs() < 0.01);
+        assert_eq!(TouchPoint::ORIGIN.angle(), UnitAngle::ZERO);
+    }
+
+    #[test]
+    fn vector_magnitude() {
+        let point = TouchPoint::new(3, -4);
+        assert_eq!(point.magnitude_squared(), 25);
+        assert!((point.magnitude() - 5.0).abs() < 0.001);
+        assert_eq!(TouchPoint::ORIGIN.magnitude_squared(), 0);
+
+        let max = TouchPoint::new(i32::MAX, i32::MIN + 1);
+        assert_eq!(max.magnitude_squared(), 2 * i64::from(i32::MAX).pow(2));
+        let min = TouchPoint::new(i32::MIN, i32::MIN);
+        assert_eq!(min.magnitude_squared(), i64::MAX);
+    }
+
+    #[test]
+    fn manhattan_and_l_infinity_distance() {
+        let a = TouchPoint::new(-3, 5);
+        let b = TouchPoint::new(4, -1);
+        assert_eq!(a.manhattan_distance(&b), 13);
+        assert_eq!(a.l_infinity_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 13);
+        assert_eq!(b.l_infinity_distance(&a), 7);
+
+        let min = TouchPoint::new(i32::MIN, i32::MIN);
+        let max = TouchPoint::new(i32::MAX, i32::MAX);
+        assert_eq!(min.manhattan_distance(&max), 2 * u64::from(u32::MAX));
+        assert_eq!(min.l_infinity_distance(&max), u32::MAX);
+    }
+
+    #[test]
+    fn scale_touch_point() {
+        let point = TouchPoint::new(10, 20);
+        assert_eq!(point * 3, TouchPoint::new(30, 60));
+        assert_eq!(point * 3 / 3, point);
+
+        let mut scaled = point;
+        scaled *= 3;
+        assert_eq!(scaled, TouchPoint::new(30, 60));
+        scaled /= 4;
+        assert_eq!(scaled, TouchPoint::new(7, 15));
+
+        assert_eq!(TouchPoint::new(-7, 7) / 2, TouchPoint::new(-3, 3));
+    }
+
+    #[test]
+    fn midpoint_at_extremes() {
+        let max = TouchPoint::new(i32::MAX, i32::MAX);
+        assert_eq!(max.midpoint(&max), max);
+
+        let min = TouchPoint::new(i32::MIN, i32::MIN);
+        assert_eq!(min.midpoint(&max), TouchPoint::new(0, 0));
+
+        let a = TouchPoint::new(-3, 3);
+        let b = TouchPoint::new(0, 0);
+        assert_eq!(a.midpoint(&b), TouchPoint::new(-1, 1));
+    }
+
+    #[test]
+    fn lerp_between_points() {
+        let a = TouchPoint::new(10, -20);
+        let b = TouchPoint::new(20, 20);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 0.5), TouchPoint::new(15, 0));
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, -1.0), a);
+        assert_eq!(a.lerp(&b, 2.0), b);
+        assert_eq!(a.lerp(&b, 0.25), TouchPoint::new(12, -10));
+    }
+
+    #[test]
+    fn touch_point_tuple_and_array_conversions() {
+        let point = TouchPoint::from((12, -34));
+        assert_eq!(point, TouchPoint::new(12, -34));
+        assert_eq!(<(i32, i32)>::from(point), (12, -34));
+
+        let point = TouchPoint::from([56, -78]);
+        assert_eq!(point, TouchPoint::new(56, -78));
+        assert_eq!(<[i32; 2]>::from(point), [56, -78]);
+
+        let round_trip: TouchPoint = <[i32; 2]>::from(TouchPoint::from((1, 2))).into();
+        assert_eq!(round_trip, TouchPoint::new(1, 2));
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-graphics")]
+    fn embedded_graphics_point_round_trip() {
+        let point = embedded_graphics_core::geometry::Point::new(-12, 345);
+        let touch_point = TouchPoint::from(point);
+        assert_eq!(touch_point, TouchPoint::new(-12, 345));
+        assert_eq!(
+            embedded_graphics_core::geometry::Point::from(touch_point),
+            point
+        );
+    }
+
+    #[test]
+    fn rect_contains_is_half_open() {
+        let rect = Rect::new(TouchPoint::new(10, 20), 30, 40);
+
+        assert!(rect.contains(TouchPoint::new(10, 20)));
+        assert!(rect.contains(TouchPoint::new(39, 59)));
+        assert!(rect.contains(TouchPoint::new(10, 59)));
+        assert!(rect.contains(TouchPoint::new(39, 20)));
+
+        assert!(!rect.contains(TouchPoint::new(40, 30)));
+        assert!(!rect.contains(TouchPoint::new(20, 60)));
+        assert!(!rect.contains(TouchPoint::new(9, 30)));
+        assert!(!rect.contains(TouchPoint::new(20, 19)));
+
+        assert!(!rect.contains(TouchPoint::new(9, 19)));
+        assert!(!rect.contains(TouchPoint::new(40, 19)));
+        assert!(!rect.contains(TouchPoint::new(9, 60)));
+        assert!(!rect.contains(TouchPoint::new(40, 60)));
+
+        assert!(!Rect::new(TouchPoint::new(0, 0), 0, 10).contains(TouchPoint::new(0, 0)));
+    }
+
+    #[test]
+    fn rect_clamp_pulls_points_inside() {
+        let rect = Rect::new(TouchPoint::new(10, 20), 30, 40);
+
+        assert_eq!(rect.clamp(TouchPoint::new(15, 25)), TouchPoint::new(15, 25));
+        assert_eq!(rect.clamp(TouchPoint::new(0, 0)), TouchPoint::new(10, 20));
+        assert_eq!(
+            rect.clamp(TouchPoint::new(100, 100)),
+            TouchPoint::new(39, 59)
+        );
+        assert_eq!(rect.clamp(TouchPoint::new(-5, 45)), TouchPoint::new(10, 45));
+
+        let far = Rect::new(TouchPoint::new(i32::MAX - 1, 0), u32::MAX, 1);
+        assert_eq!(
+            far.clamp(TouchPoint::new(i32::MAX, 5)),
+            TouchPoint::new(i32::MAX, 0)
+        );
+        assert!(far.contains(TouchPoint::new(i32::MAX, 0)));
+    }
+
+    #[test]
+    fn rotate_around_quarter_and_half_turns() {
+        let origin = TouchPoint::new(0, 0);
+        let point = TouchPoint::new(10, 3);
+        let quarter = UnitAngle::from_degrees(90);
+        let half = UnitAngle::from_degrees(180);
+
+        assert_eq!(
+            point.rotate_around(origin, &quarter),
+            TouchPoint::new(-3, 10)
+        );
+        assert_eq!(point.rotate_around(origin, &half), TouchPoint::new(-10, -3));
+
+        let center = TouchPoint::new(100, 50);
+        let point = TouchPoint::new(110, 53);
+        assert_eq!(
+            point.rotate_around(center, &quarter),
+            TouchPoint::new(97, 60)
+        );
+        assert_eq!(point.rotate_around(center, &half), TouchPoint::new(90, 47));
+        assert_eq!(center.rotate_around(center, &quarter), center);
+    }
+
+    #[test]
+    fn rotate_around_rounds_to_nearest() {
+        let point = TouchPoint::new(100, 0);
+        let rotated = point.rotate_around(TouchPoint::new(0, 0), &UnitAngle::from_degrees(30));
+        // (100 cos 30°, 100 sin 30°) = (86.60, 50.00)
+        assert_eq!(rotated, TouchPoint::new(87, 50));
+    }
+
+    #[test]
+    fn pointer_button_indices() {
+        for index in 0..4 {
+            let button = PointerButton::from_index(index).unwrap();
+            assert_eq!(button.to_index(), index);
+        }
+        assert_eq!(PointerButton::from_index(1), Some(PointerButton::Primary));
+        assert_eq!(PointerButton::from_index(3), Some(PointerButton::Tertiary));
+        assert_eq!(PointerButton::from_index(4), None);
+        assert_eq!(PointerButton::from_index(u8::MAX), None);
+    }
+
+    #[test]
+    fn clamp_to_panel_bounds() {
+        assert_eq!(
+            TouchPoint::new(-5, -1).clamp_to(800, 480),
+            TouchPoint::new(0, 0)
+        );
+        assert_eq!(
+            TouchPoint::new(800, 480).clamp_to(800, 480),
+            TouchPoint::new(799, 479)
+        );
+        assert_eq!(
+            TouchPoint::new(1000, -20).clamp_to(800, 480),
+            TouchPoint::new(799, 0)
+        );
+        assert_eq!(
+            TouchPoint::new(12, 34).clamp_to(800, 480),
+            TouchPoint::new(12, 34)
+        );
+        assert_eq!(
+            TouchPoint::new(12, 34).clamp_to(0, 0),
+            TouchPoint::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn stylus_orientation_vector() {
+        let stylus = |tilt: Option<u16>, azimuth: Option<u16>| Tool::Stylus {
+            pressure: None,
+            barrel_pressure: None,
+            tilt: tilt.map(UnitAngle::from_degrees),
+            azimuth: azimuth.map(UnitAngle::from_degrees),
+        };
+        let close = |(x, y, z): (f32, f32, f32), expected: (f32, f32, f32)| {
+            (x - expected.0).abs() < 1e-3
+                && (y - expected.1).abs() < 1e-3
+                && (z - expected.2).abs() < 1e-3
+        };
+
+        let normal = stylus(Some(0), Some(123)).orientation_vector().unwrap();
+        assert!(close(normal, (0.0, 0.0, 1.0)), "{normal:?}");
+
+        let toward_top = stylus(Some(30), Some(0)).orientation_vector().unwrap();
+        assert!(close(toward_top, (0.0, -0.5, 0.866)), "{toward_top:?}");
+
+        let toward_right = stylus(Some(90), Some(90)).orientation_vector().unwrap();
+        assert!(close(toward_right, (1.0, 0.0, 0.0)), "{toward_right:?}");
+
+        assert_eq!(stylus(Some(30), None).orientation_vector(), None);
+        assert_eq!(stylus(None, Some(30)).orientation_vector(), None);
+        assert_eq!(Tool::Finger.orientation_vector(), None);
+    }
+
+    #[test]
+    fn dominant_axis_and_direction() {
+        let horizontal = TouchPoint::new(-30, 5);
+        assert_eq!(horizontal.dominant_axis(), Axis::Horizontal);
+        assert_eq!(horizontal.dominant_direction(), Direction::Left);
+
+        let vertical = TouchPoint::new(4, -50);
+        assert_eq!(vertical.dominant_axis(), Axis::Vertical);
+        assert_eq!(vertical.dominant_direction(), Direction::Up);
+        assert_eq!((-vertical).dominant_direction(), Direction::Down);
+
+        let tie = TouchPoint::new(20, -20);
+        assert_eq!(tie.dominant_axis(), Axis::Horizontal);
+        assert_eq!(tie.dominant_direction(), Direction::Right);
+        assert_eq!(TouchPoint::new(0, 0).dominant_direction(), Direction::Right);
+    }
+
+    #[test]
+    fn float_points_add_and_scale() {
+        let a = Point {
+            x: 1.25_f32,
+            y: -0.5,
+        };
+        let b = Point::from((0.5_f32, 2.0));
+        assert_eq!(a + b, Point { x: 1.75, y: 1.5 });
+        assert_eq!((a - b) * 2.0, Point { x: 1.5, y: -5.0 });
+
+        let mut c = a;
+        c += b;
+        c /= 0.5;
+        assert_eq!(c, Point { x: 3.5, y: 3.0 });
+    }
+
+    #[test]
+    fn fixed_points_keep_sub_pixel_precision() {
+        let a = Point {
+            x: U17F15::from_num(10.25),
+            y: U17F15::from_num(3.5),
+        };
+        let b = Point {
+            x: U17F15::from_num(0.125),
+            y: U17F15::from_num(0.25),
+        };
+        let sum = a + b;
+        assert_eq!(sum.x, U17F15::from_num(10.375));
+        assert_eq!(sum.y, U17F15::from_num(3.75));
+    }
+
+    #[test]
+    fn sin_cos_matches_float() {
+        let cases = [
+            (0, 0.0, 1.0),
+            (30, 0.5, 0.866_025_4),
+            (90, 1.0, 0.0),
+            (180, 0.0, -1.0),
+            (270, -1.0, 0.0),
+        ];
+        for (degrees, expected_sin, expected_cos) in cases {
+            let (sin, cos) = UnitAngle::from_degrees(degrees).sin_cos();
+            assert!(
+                (sin.to_num::<f32>() - expected_sin).abs() < 1e-3,
+                "sin {degrees}° = {sin}"
+            );
+            assert!(
+                (cos.to_num::<f32>() - expected_cos).abs() < 1e-3,
+                "cos {degrees}° = {cos}"
+            );
+        }
+    }
+
+    #[test]
+    fn signed_pi_radians_round_trip() {
+        use fixed::types::I1F15;
+
+        let cases = [
+            (0x0000, I1F15::ZERO),
+            (0x4000, I1F15::from_num(0.5)),
+            (0x8000, I1F15::from_num(-1)),
+            (0xC000, I1F15::from_num(-0.5)),
+        ];
+        for (raw, signed) in cases {
+            let angle = UnitAngle::from_raw(raw);
+            assert_eq!(angle.as_signed_pi_radians(), signed);
+            assert_eq!(UnitAngle::from_signed_pi_radians(signed), angle);
+        }
+        let almost_full = UnitAngle::from_raw(0xFFFF);
+        assert_eq!(almost_full.as_signed_pi_radians(), -I1F15::DELTA);
+    }
+
+    #[test]
+    fn angle_approx_eq_wraps() {
+        // 100 raw units either side of zero, 200 apart across the wrap
+        let near_zero = UnitAngle::from_raw(100);
+        let near_full = UnitAngle::from_raw(0u16.wrapping_sub(100));
+        let tolerance = UnitAngle::from_raw(200);
+
+        assert!(near_zero.approx_eq(&near_full, tolerance));
+        assert!(near_full.approx_eq(&near_zero, tolerance));
+        assert!(!near_zero.approx_eq(&near_full, UnitAngle::from_raw(199)));
+        assert!(!near_zero.approx_eq(&UnitAngle::from_degrees(180), tolerance));
+        assert!(near_zero.approx_eq(&near_zero, UnitAngle::from_raw(0)));
+    }
+
+    #[test]
+    fn point_approx_eq_checks_each_axis() {
+        let point = TouchPoint::new(10, -10);
+        assert!(point.approx_eq(&TouchPoint::new(12, -8), 2));
+        assert!(!point.approx_eq(&TouchPoint::new(12, -7), 2));
+        assert!(!point.approx_eq(&TouchPoint::new(7, -10), 2));
+        assert!(!point.approx_eq(&point, -1));
+    }
+
+    #[test]
+    fn angle_addition_and_subtraction_wrap() {
+        let sum = UnitAngle::from_degrees(350) + UnitAngle::from_degrees(20);
+        assert!((sum.as_degrees_f32() - 10.0).abs() < 0.01);
+
+        let difference = UnitAngle::from_degrees(10) - UnitAngle::from_degrees(20);
+        assert!((difference.as_degrees_f32() - 350.0).abs() < 0.01);
+
+        let mut angle = UnitAngle::from_degrees(270);
+        angle += UnitAngle::from_degrees(180);
+        assert!((angle.as_degrees_f32() - 90.0).abs() < 0.01);
+        angle -= UnitAngle::from_degrees(90);
+        assert_eq!(angle, UnitAngle::from_degrees(0));
+    }
+
+    #[test]
+    fn i16f16_radians_match_float() {
+        use fixed::types::I16F16;
+
+        for raw in (0..=0xFFFF).step_by(97) {
+            let angle = UnitAngle::from_raw(raw);
+            let radians = angle.as_i16f16_radians();
+            assert!((radians.to_num::<f32>() - angle.as_radians_f32()).abs() < 0.001);
+            assert_eq!(UnitAngle::from_i16f16_radians(radians), angle);
+        }
+
+        let quarter = UnitAngle::from_i16f16_radians(I16F16::FRAC_PI_2);
+        assert_eq!(quarter, UnitAngle::HALF_PI);
+        let wrapped = UnitAngle::from_i16f16_radians(-I16F16::FRAC_PI_2);
+        assert_eq!(wrapped, UnitAngle::THREE_HALF_PI);
+        let many_turns = UnitAngle::from_i16f16_radians(I16F16::TAU * 3 + I16F16::PI);
+        assert!((many_turns.as_degrees_f32() - 180.0).abs() < 0.01);
+        assert_eq!(UnitAngle::PI.as_i16f16_radians(), I16F16::PI);
+    }
+
+    #[test]
+    fn clamped_angles() {
+        let clamped = UnitAngle::from_degrees_clamped(120, 0, 90);
+        assert_eq!(clamped, UnitAngle::from_degrees(90));
+        assert_eq!(UnitAngle::from_degrees_clamped(-30, 0, 90), UnitAngle::ZERO);
+        assert_eq!(
+            UnitAngle::from_degrees_clamped(45.0, 0, 90),
+            UnitAngle::from_degrees(45)
+        );
+        assert_eq!(
+            UnitAngle::from_degrees_clamped(1e9, 0, 90),
+            UnitAngle::from_degrees(90)
+        );
+        let negative = UnitAngle::from_degrees_clamped(-30, -45, 0);
+        assert!((negative.as_degrees_f32() - 330.0).abs() < 0.01);
+        assert_eq!(
+            UnitAngle::from_degrees_clamped(0, 10, 5),
+            UnitAngle::from_degrees(5)
+        );
+
+        let clamped = UnitAngle::from_radians_clamped(2.0, 0, 1.5);
+        assert!((clamped.as_radians_f32() - 1.5).abs() < 0.001);
+        let clamped = UnitAngle::from_radians_clamped(-2.0, -1.0, 1.0);
+        assert!((clamped.as_radians_f32() - (2.0 * core::f32::consts::PI - 1.0)).abs() < 0.001);
+
+        let tilt = UnitAngle::from_degrees(120);
+        let range = (UnitAngle::ZERO, UnitAngle::from_degrees(90));
+        assert_eq!(tilt.clamp(range.0, range.1), range.1);
+        assert_eq!(
+            UnitAngle::from_degrees(30).clamp(range.0, range.1),
+            UnitAngle::from_degrees(30)
+        );
+        assert_eq!(
+            UnitAngle::from_degrees(30).clamp(UnitAngle::PI, range.1),
+            range.1
+        );
+    }
+
+    #[test]
+    fn scale_angle() {
+        let quarter = UnitAngle::from_degrees(90);
+        assert_eq!(quarter.scale(2.0), UnitAngle::from_degrees(180));
+        assert_eq!(quarter.scale(0.5), UnitAngle::from_degrees(45));
+        assert_eq!(quarter.scale(5.0), quarter);
+        assert_eq!(quarter.scale(-1.0), UnitAngle::from_degrees(270));
+        assert_eq!(quarter.scale(f32::NAN), UnitAngle::ZERO);
+        assert_eq!(UnitAngle::from_raw(3).scale(0.5), UnitAngle::from_raw(2));
+
+        assert_eq!(quarter.scale_by(2), UnitAngle::from_degrees(180));
+        assert_eq!(quarter.scale_by(5), quarter);
+        assert_eq!(UnitAngle::from_degrees(270).scale_by(3), quarter);
+        assert_eq!(quarter.scale_by(0), UnitAngle::ZERO);
+    }
+
+    #[test]
+    fn negate_angle() {
+        assert_eq!(-UnitAngle::from_degrees(0), UnitAngle::from_degrees(0));
+        assert_eq!(-UnitAngle::from_degrees(90), UnitAngle::from_degrees(270));
+        assert_eq!(-UnitAngle::from_degrees(180), UnitAngle::from_degrees(180));
+        assert_eq!(-UnitAngle::from_raw(1), UnitAngle::from_raw(0xFFFF));
+    }
+
+    #[test]
+    #[expect(clippy::cast_precision_loss)]
+    fn signed_difference_takes_shortest_path() {
+        let degrees = |delta: i32| delta as f32 * 180.0 / 32768.0;
+
+        let a = UnitAngle::from_degrees(350);
+        let b = UnitAngle::from_degrees(10);
+        assert!((degrees(a.signed_difference(&b)) - 20.0).abs() < 0.01);
+        assert!((degrees(b.signed_difference(&a)) + 20.0).abs() < 0.01);
+        assert_eq!(a.signed_difference(&a), 0);
+
+        let zero = UnitAngle::from_degrees(0);
+        let half = UnitAngle::from_degrees(180);
+        assert_eq!(zero.signed_difference(&half), 32768);
+        assert_eq!(half.signed_difference(&zero), 32768);
+
+        let just_past_half = UnitAngle::from_pi_radians(fixed::types::U1F15::from_bits(0x8001));
+        assert_eq!(zero.signed_difference(&just_past_half), -32767);
+    }
+
+    #[test]
+    fn const_raw_angles() {
+        const ORIENTATIONS: [UnitAngle; 3] = [
+            UnitAngle::from_raw(0x0000),
+            UnitAngle::from_raw(0x4000),
+            UnitAngle::from_raw(0x8000),
+        ];
+        assert_eq!(ORIENTATIONS[0], UnitAngle::from_degrees(0));
+        assert_eq!(ORIENTATIONS[1], UnitAngle::from_degrees(90));
+        assert_eq!(ORIENTATIONS[2], UnitAngle::from_degrees(180));
+        assert_eq!(UnitAngle::from_degrees(270).as_raw(), 0xC000);
+    }
+
+    #[test]
+    fn angle_constants() {
+        assert_eq!(
+            UnitAngle::PI.as_pi_radians(),
+            fixed::types::U1F15::from_num(1.0)
+        );
+        assert_eq!(UnitAngle::ZERO, UnitAngle::from_degrees(0));
+        assert_eq!(UnitAngle::HALF_PI, UnitAngle::from_degrees(90));
+        assert_eq!(UnitAngle::THREE_HALF_PI, UnitAngle::from_degrees(270));
+        assert_eq!(UnitAngle::HALF_PI + UnitAngle::PI, UnitAngle::THREE_HALF_PI);
+    }
+
+    #[test]
+    fn sort_angles() {
+        let degrees = [270, -1, 90, 0, 180];
+        let mut angles = degrees.map(UnitAngle::from_degrees);
+        angles.sort();
+        assert_eq!(angles, [0, 90, 180, 270, -1].map(UnitAngle::from_degrees));
+    }
+
+    #[test]
+    fn phase_predicates() {
+        let cases = [
+            (Phase::Started, false, true, false),
+            (Phase::Moved, false, true, false),
+            (Phase::Stationary, false, true, false),
+            (Phase::Ended, true, false, false),
+            (Phase::Cancelled, true, false, false),
+            (Phase::Hovering(None), false, false, true),
+            (Phase::Hovering(Some(3)), false, false, true),
+        ];
+        for (phase, terminal, active, hovering) in cases {
+            assert_eq!(phase.is_terminal(), terminal, "{phase:?}");
+            assert_eq!(phase.is_active(), active, "{phase:?}");
+            assert_eq!(phase.is_hovering(), hovering, "{phase:?}");
+        }
+    }
+
+    #[test]
+    fn phase_proximity() {
+        assert_eq!(Phase::Hovering(Some(5)).proximity(), Some(5));
+        assert_eq!(Phase::Hovering(None).proximity(), None);
+        assert_eq!(Phase::Moved.proximity(), None);
+    }
+
+    #[test]
+    fn tool_accessors() {
+        let finger = Tool::Finger;
+        assert!(finger.is_finger() && !finger.is_pointer() && !finger.is_stylus());
+        assert_eq!(finger.pressure(), None);
+        assert_eq!(finger.tilt(), None);
+        assert_eq!(finger.azimuth(), None);
+
+        let pointer = Tool::Pointer {
+            button: PointerButton::Primary,
+            wheel_delta: None,
+        };
+        assert!(!pointer.is_finger() && pointer.is_pointer() && !pointer.is_stylus());
+        assert_eq!(pointer.pressure(), None);
+        assert_eq!(pointer.tilt(), None);
+        assert_eq!(pointer.azimuth(), None);
+
+        let stylus = Tool::Stylus {
+            pressure: Some(120),
+            barrel_pressure: None,
+            tilt: Some(UnitAngle::from_degrees(30)),
+            azimuth: None,
+        };
+        assert!(!stylus.is_finger() && !stylus.is_pointer() && stylus.is_stylus());
+        assert_eq!(stylus.pressure(), Some(120));
+        assert_eq!(stylus.tilt(), Some(UnitAngle::from_degrees(30)));
+        assert_eq!(stylus.azimuth(), None);
+        assert_eq!(stylus.barrel_pressure(), None);
+    }
+
+    #[test]
+    fn tool_constructors_match_manual_construction() {
+        let location = TouchPoint::new(3, 4);
+        assert_eq!(
+            Touch::finger(1, location, Phase::Moved),
+            Touch {
+                id: 1,
+                location,
+                phase: Phase::Moved,
+                tool: Tool::Finger,
+            }
+        );
+        assert_eq!(
+            Touch::pointer(2, location, PointerButton::Secondary, Phase::Started),
+            Touch {
+                id: 2,
+                location,
+                phase: Phase::Started,
+                tool: Tool::Pointer {
+                    button: PointerButton::Secondary,
+                    wheel_delta: None,
+                },
+            }
+        );
+        assert_eq!(
+            Touch::stylus(3, location, Some(250), Phase::Hovering(None)),
+            Touch {
+                id: 3,
+                location,
+                phase: Phase::Hovering(None),
+                tool: Tool::Stylus {
+                    pressure: Some(250),
+                    barrel_pressure: None,
+                    tilt: None,
+                    azimuth: None,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn pointer_reports_wheel_delta() {
+        let scrolled = Tool::Pointer {
+            button: PointerButton::None,
+            wheel_delta: Some((0, -3)),
+        };
+        assert_eq!(scrolled.wheel_delta(), Some((0, -3)));
+        let touch = Touch::new(0, TouchPoint::new(5, 5), Phase::Hovering(None), scrolled);
+        assert!(touch.tool.is_pointer());
+
+        let clicked = Tool::Pointer {
+            button: PointerButton::Primary,
+            wheel_delta: None,
+        };
+        assert_eq!(clicked.wheel_delta(), None);
+        assert_eq!(Tool::Finger.wheel_delta(), None);
+    }
+
+    #[test]
+    fn eraser_is_distinct_from_stylus() {
+        let eraser = Tool::Eraser {
+            pressure: Some(200),
+            tilt: Some(UnitAngle::from_degrees(20)),
+            azimuth: None,
+        };
+        assert!(eraser.is_eraser() && !eraser.is_stylus() && !eraser.is_finger());
+        assert_eq!(eraser.pressure(), Some(200));
+        assert_eq!(eraser.tilt(), Some(UnitAngle::from_degrees(20)));
+        assert_eq!(eraser.azimuth(), None);
+        assert_eq!(eraser.barrel_pressure(), None);
+
+        let touch = Touch::new(0, TouchPoint::new(1, 2), Phase::Moved, eraser);
+        let stylus = Tool::Stylus {
+            pressure: Some(200),
+            barrel_pressure: None,
+            tilt: Some(UnitAngle::from_degrees(20)),
+            azimuth: None,
+        };
+        assert!(!stylus.is_eraser());
+        assert_ne!(touch.tool, stylus);
+    }
+
+    #[test]
+    fn barrel_pressure_is_independent_of_tip() {
+        let stylus = Tool::Stylus {
+            pressure: None,
+            barrel_pressure: Some(75),
+            tilt: None,
+            azimuth: None,
+        };
+        assert_eq!(stylus.barrel_pressure(), Some(75));
+        assert_eq!(stylus.pressure(), None);
+        assert_eq!(stylus.normalized_pressure(100), None);
+        assert_eq!(Tool::Finger.barrel_pressure(), None);
+    }
+
+    #[test]
+    fn normalized_pressure() {
+        let stylus = |pressure| Tool::Stylus {
+            pressure,
+            barrel_pressure: None,
+            tilt: None,
+            azimuth: None,
+        };
+        assert_eq!(stylus(Some(100)).normalized_pressure(400), Some(0.25));
+        assert_eq!(stylus(Some(500)).normalized_pressure(400), Some(1.0));
+        assert_eq!(stylus(Some(100)).normalized_pressure(0), None);
+        assert_eq!(stylus(None).normalized_pressure(400), None);
+        assert_eq!(Tool::Finger.normalized_pressure(400), None);
+    }
+
+    #[test]
+    fn pointer_buttons_combined_state() {
+        let mut buttons = PointerButtons::from(PointerButton::Primary);
+        buttons.insert(PointerButtons::SECONDARY);
+        assert!(buttons.contains(PointerButtons::PRIMARY));
+        assert!(buttons.contains(PointerButtons::SECONDARY));
+        assert!(buttons.contains(PointerButtons::PRIMARY | PointerButtons::SECONDARY));
+        assert!(!buttons.contains(PointerButtons::TERTIARY));
+        assert!(!buttons.contains(PointerButtons::PRIMARY | PointerButtons::TERTIARY));
+
+        buttons.remove(PointerButtons::PRIMARY);
+        assert_eq!(buttons, PointerButtons::SECONDARY);
+        buttons.remove(PointerButtons::SECONDARY);
+        assert!(buttons.is_empty());
+    }
+
+    #[test]
+    fn pointer_buttons_empty_state() {
+        let buttons = PointerButtons::from(PointerButton::None);
+        assert!(buttons.is_empty());
+        assert_eq!(buttons, PointerButtons::default());
+        assert!(buttons.contains(PointerButtons::empty()));
+        assert!(!buttons.contains(PointerButtons::PRIMARY));
+    }
+
+    #[test]
+    fn contact_size() {
+        let contact = Tool::Contact {
+            major_axis: 12,
+            minor_axis: 8,
+        };
+        assert!(contact.is_finger());
+        assert_eq!(contact.contact_size(), Some((12, 8)));
+        assert_eq!(Tool::Finger.contact_size(), None);
+    }
+
+    #[test]
+    fn default_touch_is_inactive() {
+        assert_eq!(TouchPoint::default(), TouchPoint::ORIGIN);
+        assert_eq!(Point::<f32>::default(), Point { x: 0.0, y: 0.0 });
+
+        let touch = Touch::default();
+        assert!(touch.phase.is_terminal());
+        assert!(!touch.phase.is_active());
+        assert_eq!(touch.location, TouchPoint::ORIGIN);
+        assert_eq!((touch.id, touch.tool), (0, Tool::Finger));
+    }
+
+    #[test]
+    fn touch_eq_ignoring_id() {
+        let touch = Touch::new(1, TouchPoint::new(5, 6), Phase::Moved, Tool::Finger);
+        let mut other = touch.clone();
+        other.id = 9;
+        assert_ne!(touch, other);
+        assert!(touch.eq_ignoring_id(&other));
+
+        other.phase = Phase::Ended;
+        assert!(!touch.eq_ignoring_id(&other));
+    }
+
+    #[test]
+    fn timed_touch_conversions() {
+        let touch = Touch::new(1, TouchPoint::new(5, 6), Phase::Started, Tool::Finger);
+        let timed = TimedTouch::from((touch.clone(), 1234));
+        assert_eq!(timed, TimedTouch::new(touch.clone(), 1234));
+        assert_eq!(timed.timestamp_ms, 1234);
+        assert_eq!(Touch::from(timed), touch);
+    }
 }
@@ -0,0 +1,140 @@
+//! A scripted touch device for testing touch handling code
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::{
+    Touch,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// A touch device that replays a scripted sequence of frames
+///
+/// Each read returns the next frame in the order they were pushed, and fails with
+/// [`NoMoreFrames`] once all frames have been consumed.
+///
+/// ```
+/// # use embedded_touch::{Phase, Tool, Touch, TouchPoint};
+/// # use embedded_touch::{mock::{MockTouchDevice, NoMoreFrames}, traits::TouchInputDevice};
+/// let mut device = MockTouchDevice::new();
+/// let swipe = [(Phase::Started, 10), (Phase::Moved, 60), (Phase::Ended, 110)];
+/// for (phase, x) in swipe {
+///     device.push_frame([Touch::new(0, TouchPoint::new(x, 50), phase, Tool::Finger)]);
+/// }
+///
+/// let mut distance = 0;
+/// let mut start = None;
+/// while let Ok(touches) = device.touches() {
+///     for touch in touches {
+///         match touch.phase {
+///             Phase::Started => start = Some(touch.location),
+///             Phase::Ended => distance = touch.location.x - start.unwrap().x,
+///             _ => {}
+///         }
+///     }
+/// }
+/// assert_eq!(distance, 100);
+/// assert_eq!(device.touches().err(), Some(NoMoreFrames));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockTouchDevice {
+    frames: VecDeque<Vec<Touch>>,
+    current: Vec<Touch>,
+}
+
+impl MockTouchDevice {
+    /// Create a device with no scripted frames
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a frame to be returned after all previously pushed frames
+    pub fn push_frame(&mut self, touches: impl IntoIterator<Item = Touch>) {
+        self.frames.push_back(touches.into_iter().collect());
+    }
+
+    /// Returns the number of frames that have not been read yet
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.frames.len()
+    }
+
+    fn next_frame(&mut self) -> Result<&[Touch], NoMoreFrames> {
+        self.current = self.frames.pop_front().ok_or(NoMoreFrames)?;
+        Ok(&self.current)
+    }
+}
+
+impl TouchInputDevice for MockTouchDevice {
+    type Error = NoMoreFrames;
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        self.next_frame()
+    }
+}
+
+impl AsyncTouchInputDevice for MockTouchDevice {
+    type Error = NoMoreFrames;
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        self.next_frame()
+    }
+}
+
+/// Error returned by [`MockTouchDevice`] once every scripted frame has been read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoMoreFrames;
+
+impl core::fmt::Display for NoMoreFrames {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("no more scripted frames")
+    }
+}
+
+impl core::error::Error for NoMoreFrames {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, Tool, TouchPoint, test_util::block_on};
+
+    fn touch(id: u8, phase: Phase) -> Touch {
+        Touch::new(id, TouchPoint::new(id, id), phase, Tool::Finger)
+    }
+
+    #[test]
+    fn replays_frames_in_order() {
+        let mut device = MockTouchDevice::new();
+        device.push_frame([touch(0, Phase::Started)]);
+        device.push_frame([touch(0, Phase::Moved), touch(1, Phase::Started)]);
+        device.push_frame([]);
+        assert_eq!(device.remaining(), 3);
+
+        let frame: Vec<Touch> = TouchInputDevice::touches(&mut device)
+            .unwrap()
+            .into_iter()
+            .cloned()
+            .collect();
+        assert_eq!(frame, [touch(0, Phase::Started)]);
+
+        let frame: Vec<Touch> = block_on(AsyncTouchInputDevice::touches(&mut device))
+            .unwrap()
+            .into_iter()
+            .cloned()
+            .collect();
+        assert_eq!(frame, [touch(0, Phase::Moved), touch(1, Phase::Started)]);
+
+        let frame = TouchInputDevice::touches(&mut device).unwrap();
+        assert_eq!(frame.into_iter().count(), 0);
+
+        assert_eq!(
+            TouchInputDevice::touches(&mut device).err(),
+            Some(NoMoreFrames)
+        );
+        assert_eq!(
+            block_on(AsyncTouchInputDevice::touches(&mut device)).err(),
+            Some(NoMoreFrames)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! A touch device that never reports touches
+
+use core::convert::Infallible;
+
+use crate::{
+    Touch,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// A touch device that always reports no touches, for builds without touch hardware
+///
+/// Reads never fail, and the async read is ready immediately.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NullTouchDevice;
+
+impl TouchInputDevice for NullTouchDevice {
+    type Error = Infallible;
+
+    const MAX_TOUCHES: usize = 0;
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        Ok(core::iter::empty())
+    }
+}
+
+impl AsyncTouchInputDevice for NullTouchDevice {
+    type Error = Infallible;
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        Ok(core::iter::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::block_on;
+
+    #[test]
+    fn yields_nothing() {
+        let mut device = NullTouchDevice;
+        let Ok(touches) = TouchInputDevice::touches(&mut device);
+        assert_eq!(touches.into_iter().count(), 0);
+
+        let Ok(touches) = block_on(AsyncTouchInputDevice::touches(&mut device));
+        assert_eq!(touches.into_iter().count(), 0);
+    }
+}
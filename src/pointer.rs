@@ -0,0 +1,91 @@
+//! Lowering of touches into simple pointer events, for UI toolkits
+
+use crate::{Phase, Touch, TouchPoint};
+
+/// A minimal pointer event, carrying only the kind of event and its location
+///
+/// This drops the ID, tool, and hover proximity of a [`Touch`], for UI code that only
+/// understands a single pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PointerEvent {
+    /// The pointer made contact
+    Down {
+        /// Location of the event
+        location: TouchPoint,
+    },
+    /// The pointer remains in contact, and may have moved
+    Move {
+        /// Location of the event
+        location: TouchPoint,
+    },
+    /// The pointer lifted
+    Up {
+        /// Location of the event
+        location: TouchPoint,
+    },
+    /// The interaction was cancelled, and should not be treated as a release
+    Cancel {
+        /// Location of the event
+        location: TouchPoint,
+    },
+    /// The pointer is hovering without contact
+    Hover {
+        /// Location of the event
+        location: TouchPoint,
+    },
+}
+
+impl PointerEvent {
+    /// Returns the location of the event
+    #[must_use]
+    pub fn location(&self) -> TouchPoint {
+        match *self {
+            PointerEvent::Down { location }
+            | PointerEvent::Move { location }
+            | PointerEvent::Up { location }
+            | PointerEvent::Cancel { location }
+            | PointerEvent::Hover { location } => location,
+        }
+    }
+}
+
+/// Maps the phase of the touch onto an event, with [`Phase::Stationary`] as
+/// [`PointerEvent::Move`]
+impl From<&Touch> for PointerEvent {
+    fn from(touch: &Touch) -> Self {
+        let location = touch.location;
+        match touch.phase {
+            Phase::Started => PointerEvent::Down { location },
+            Phase::Moved | Phase::Stationary => PointerEvent::Move { location },
+            Phase::Ended => PointerEvent::Up { location },
+            Phase::Cancelled => PointerEvent::Cancel { location },
+            Phase::Hovering(_) => PointerEvent::Hover { location },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tool;
+
+    #[test]
+    fn maps_each_phase() {
+        let location = TouchPoint::new(12, 34);
+        let cases = [
+            (Phase::Started, PointerEvent::Down { location }),
+            (Phase::Moved, PointerEvent::Move { location }),
+            (Phase::Stationary, PointerEvent::Move { location }),
+            (Phase::Ended, PointerEvent::Up { location }),
+            (Phase::Cancelled, PointerEvent::Cancel { location }),
+            (Phase::Hovering(Some(3)), PointerEvent::Hover { location }),
+        ];
+        for (phase, expected) in cases {
+            let event = PointerEvent::from(&Touch::new(5, location, phase, Tool::Finger));
+            assert_eq!(event, expected);
+            assert_eq!(event.location(), location);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Conversion of relative pointer motion, such as from a mouse, into absolute touches
+
+use crate::{Phase, PointerButton, Rect, Touch, TouchPoint};
+
+/// Accumulates relative pointer motion into an absolute position on a panel
+///
+/// Devices such as mice report movement deltas rather than absolute coordinates. Each
+/// delta passed to [`RelativePointer::update`] moves the position, which is clamped to
+/// the panel, and a [`Tool::Pointer`](crate::Tool::Pointer) touch at that position is
+/// returned.
+///
+/// The position starts at the center of the panel, and can be moved directly with
+/// [`RelativePointer::set_position`], e.g. to seed it from a known cursor location.
+///
+/// The phase of each touch follows the pressed button: [`Phase::Hovering`] while no
+/// button is pressed, [`Phase::Started`] when one is first pressed, [`Phase::Moved`]
+/// while any button stays pressed, and [`Phase::Ended`] on release.
+#[derive(Debug, Clone)]
+pub struct RelativePointer {
+    id: u8,
+    panel: Rect,
+    position: TouchPoint,
+    pressed: bool,
+}
+
+impl RelativePointer {
+    /// Create a pointer with the given touch ID on a `width` × `height` pixel panel
+    #[must_use]
+    pub fn new(id: u8, width: u32, height: u32) -> Self {
+        let panel = Rect::new(TouchPoint::ORIGIN, width, height);
+        let center = |size: u32| i32::try_from(size / 2).unwrap_or(i32::MAX);
+        Self {
+            id,
+            panel,
+            position: panel.clamp(TouchPoint::new(center(width), center(height))),
+            pressed: false,
+        }
+    }
+
+    /// Returns the current absolute position
+    #[must_use]
+    pub fn position(&self) -> TouchPoint {
+        self.position
+    }
+
+    /// Move the pointer to an absolute position, clamped to the panel
+    pub fn set_position(&mut self, position: TouchPoint) {
+        self.position = self.panel.clamp(position);
+    }
+
+    /// Apply a movement delta and the current button state, returning the resulting touch
+    pub fn update(&mut self, delta: TouchPoint, button: PointerButton) -> Touch {
+        self.set_position(TouchPoint::new(
+            self.position.x.saturating_add(delta.x),
+            self.position.y.saturating_add(delta.y),
+        ));
+        let pressed = button != PointerButton::None;
+        let phase = match (self.pressed, pressed) {
+            (false, false) => Phase::Hovering(None),
+            (false, true) => Phase::Started,
+            (true, true) => Phase::Moved,
+            (true, false) => Phase::Ended,
+        };
+        self.pressed = pressed;
+        Touch::pointer(self.id, self.position, button, phase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tool;
+
+    #[test]
+    fn accumulates_deltas_within_panel() {
+        let mut pointer = RelativePointer::new(0, 100, 50);
+        assert_eq!(pointer.position(), TouchPoint::new(50, 25));
+
+        let none = PointerButton::None;
+        assert_eq!(
+            pointer.update(TouchPoint::new(10, -5), none).location,
+            TouchPoint::new(60, 20)
+        );
+        pointer.update(TouchPoint::new(30, 0), none);
+        assert_eq!(
+            pointer.update(TouchPoint::new(30, -100), none).location,
+            TouchPoint::new(99, 0)
+        );
+        pointer.update(TouchPoint::new(i32::MIN, i32::MAX), none);
+        assert_eq!(pointer.position(), TouchPoint::new(0, 49));
+
+        pointer.set_position(TouchPoint::new(-5, 10));
+        assert_eq!(pointer.position(), TouchPoint::new(0, 10));
+    }
+
+    #[test]
+    fn phase_follows_buttons() {
+        let mut pointer = RelativePointer::new(3, 100, 100);
+        let delta = TouchPoint::new(1, 0);
+        let phases = [
+            (PointerButton::None, Phase::Hovering(None)),
+            (PointerButton::Primary, Phase::Started),
+            (PointerButton::Primary, Phase::Moved),
+            (PointerButton::Secondary, Phase::Moved),
+            (PointerButton::None, Phase::Ended),
+            (PointerButton::None, Phase::Hovering(None)),
+        ];
+        for (button, phase) in phases {
+            let touch = pointer.update(delta, button);
+            assert_eq!(touch.id, 3);
+            assert_eq!(touch.phase, phase);
+            assert_eq!(
+                touch.tool,
+                Tool::Pointer {
+                    button,
+                    wheel_delta: None
+                }
+            );
+        }
+        assert_eq!(pointer.position(), TouchPoint::new(56, 50));
+    }
+}
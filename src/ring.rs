@@ -0,0 +1,108 @@
+//! Fixed-capacity ring buffer of recent values, used for per-touch histories
+
+/// A buffer holding the most recent `N` values pushed to it
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RingBuffer<T, const N: usize> {
+    values: [T; N],
+    len: usize,
+    next: usize,
+}
+
+impl<T: Copy, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty buffer, with `fill` in the unused slots
+    pub(crate) const fn new(fill: T) -> Self {
+        Self {
+            values: [fill; N],
+            len: 0,
+            next: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Pushes a value, evicting the oldest if the buffer is full
+    pub(crate) fn push(&mut self, value: T) {
+        if N == 0 {
+            return;
+        }
+        self.values[self.next] = value;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Values from newest to oldest
+    pub(crate) fn newest_first(&self) -> impl DoubleEndedIterator<Item = &T> {
+        (1..=self.len).map(|age| &self.values[(self.next + N - age) % N])
+    }
+
+    pub(crate) fn newest(&self) -> Option<&T> {
+        self.newest_first().next()
+    }
+}
+
+/// A value stamped with the time it was sampled
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Sample<T> {
+    pub(crate) value: T,
+    pub(crate) timestamp_ms: u32,
+}
+
+impl<T, const N: usize> RingBuffer<Sample<T>, N> {
+    /// Returns the newest value, the oldest value sampled at most `window_ms` before it,
+    /// and the milliseconds between them
+    ///
+    /// Returns `None` if there are too few samples, or no time passed between them.
+    /// Timestamps may wrap around.
+    pub(crate) fn span(&self, window_ms: u32) -> Option<(&T, &T, u32)> {
+        let mut samples = self.newest_first();
+        let newest = samples.next()?;
+        let oldest = samples
+            .take_while(|sample| newest.timestamp_ms.wrapping_sub(sample.timestamp_ms) <= window_ms)
+            .last()?;
+        let elapsed_ms = newest.timestamp_ms.wrapping_sub(oldest.timestamp_ms);
+        (elapsed_ms != 0).then_some((&newest.value, &oldest.value, elapsed_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_most_recent_values() {
+        let mut ring = RingBuffer::<u32, 3>::new(0);
+        assert_eq!(ring.newest(), None);
+        for value in 1..=4 {
+            ring.push(value);
+        }
+        assert!(ring.newest_first().eq(&[4, 3, 2]));
+        assert!(ring.newest_first().rev().eq(&[2, 3, 4]));
+        ring.clear();
+        assert_eq!(ring.newest(), None);
+
+        let mut empty = RingBuffer::<u32, 0>::new(0);
+        empty.push(1);
+        assert_eq!(empty.newest(), None);
+    }
+
+    #[test]
+    fn span_is_limited_to_window() {
+        let mut ring = RingBuffer::<_, 4>::new(Sample {
+            value: 0,
+            timestamp_ms: 0,
+        });
+        assert_eq!(ring.span(100), None);
+        for (value, timestamp_ms) in [(1, u32::MAX - 50), (2, u32::MAX - 10), (3, 40)] {
+            ring.push(Sample {
+                value,
+                timestamp_ms,
+            });
+        }
+        assert_eq!(ring.span(60), Some((&3, &2, 51)));
+        assert_eq!(ring.span(100), Some((&3, &1, 91)));
+    }
+}
@@ -0,0 +1,50 @@
+//! [`Stream`] adapter for async touch devices
+
+use futures_util::stream::{self, Stream};
+
+use crate::{frame::TouchFrame, traits::AsyncTouchInputDevice};
+
+/// Turn an async device into a stream of owned frames
+///
+/// Each item is the result of one read, copied into a [`TouchFrame`]. Touches beyond the
+/// capacity `N` of the frame are dropped, as with
+/// [`TouchInputDevice::snapshot`](crate::traits::TouchInputDevice::snapshot), so `N`
+/// should be at least the number of touches the device reports. Errors are yielded as
+/// items, and the stream never ends, so callers decide whether to keep reading after a
+/// failure.
+pub fn frames<D: AsyncTouchInputDevice, const N: usize>(
+    device: D,
+) -> impl Stream<Item = Result<TouchFrame<N>, D::Error>> {
+    stream::unfold(device, |mut device| async move {
+        let frame = device.touches().await.map(TouchFrame::truncated);
+        Some((frame, device))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::pin::pin;
+
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::{
+        Phase, Touch,
+        test_util::{Frames, block_on, finger},
+    };
+
+    #[test]
+    fn streams_owned_frames() {
+        let script: &[&[Touch]] = &[
+            &[finger(0, 10, 0, Phase::Started)],
+            &[finger(0, 20, 0, Phase::Moved)],
+        ];
+        let mut frames = pin!(frames::<_, 2>(Frames::new(script)));
+
+        let first = block_on(frames.next()).unwrap().unwrap();
+        let second = block_on(frames.next()).unwrap().unwrap();
+        assert_eq!(first.as_slice(), [finger(0, 10, 0, Phase::Started)]);
+        assert_eq!(second.as_slice(), [finger(0, 20, 0, Phase::Moved)]);
+        assert_eq!(block_on(frames.next()), Some(Err(())));
+    }
+}
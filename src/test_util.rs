@@ -0,0 +1,52 @@
+//! Helpers shared by unit tests
+
+use core::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    Phase, Tool, Touch, TouchPoint,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// Polls a future to completion, for testing async devices that are always ready
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// A finger touch at `(x, y)`
+pub(crate) fn finger(id: u8, x: i32, y: i32, phase: Phase) -> Touch {
+    Touch::new(id, TouchPoint::new(x, y), phase, Tool::Finger)
+}
+
+/// A device that returns each frame in turn, then fails
+pub(crate) struct Frames<'a>(pub(crate) core::slice::Iter<'a, &'a [Touch]>);
+
+impl<'a> Frames<'a> {
+    pub(crate) fn new(frames: &'a [&'a [Touch]]) -> Self {
+        Self(frames.iter())
+    }
+}
+
+impl TouchInputDevice for Frames<'_> {
+    type Error = ();
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        self.0.next().copied().ok_or(())
+    }
+}
+
+impl AsyncTouchInputDevice for Frames<'_> {
+    type Error = ();
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        self.0.next().copied().ok_or(())
+    }
+}
@@ -0,0 +1,204 @@
+//! Rate limiting of touch devices that report faster than they are consumed
+
+use crate::{
+    Phase, Touch,
+    id_map::IdMap,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// A touch device adapter that drops reads arriving faster than a minimum interval
+///
+/// Each read is stamped with `clock`, which returns the current time in milliseconds and
+/// may wrap around. A read is forwarded if at least `min_interval_ms` has passed since
+/// the last forwarded read, and otherwise dropped, and the device is read again. Reads
+/// containing a [`Phase::Started`], [`Phase::Ended`], or [`Phase::Cancelled`] touch are
+/// always forwarded, so no touch lifecycle event is lost. Dropped reads only contain
+/// movement that a later read supersedes.
+///
+/// Up to `N` touches are buffered per read. Additional touches are dropped, along with any
+/// lifecycle events they carry.
+#[derive(Debug)]
+pub struct Throttled<D, C, const N: usize> {
+    device: D,
+    clock: C,
+    min_interval_ms: u32,
+    last_forwarded_ms: Option<u32>,
+    buffer: IdMap<Touch, N>,
+}
+
+impl<D, C: FnMut() -> u32, const N: usize> Throttled<D, C, N> {
+    /// Wrap a device, forwarding at most one movement-only read per `min_interval_ms`
+    #[must_use]
+    pub fn new(device: D, min_interval_ms: u32, clock: C) -> Self {
+        Self {
+            device,
+            clock,
+            min_interval_ms,
+            last_forwarded_ms: None,
+            buffer: IdMap::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped device
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the wrapped device
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the adapter, returning the wrapped device
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    /// Buffers a read, returning `true` if any of its touches is a lifecycle event
+    fn fill<'a>(
+        buffer: &mut IdMap<Touch, N>,
+        touches: impl IntoIterator<Item = &'a Touch>,
+    ) -> bool {
+        buffer.clear();
+        let mut lifecycle = false;
+        for touch in touches {
+            // Touches beyond capacity are dropped, and so cannot force a read through
+            if buffer.insert(touch.id, touch.clone()).is_ok() {
+                lifecycle |= matches!(
+                    touch.phase,
+                    Phase::Started | Phase::Ended | Phase::Cancelled
+                );
+            }
+        }
+        lifecycle
+    }
+
+    /// Returns `true` if the buffered read should be forwarded
+    fn forward(&mut self, lifecycle: bool) -> bool {
+        let now = (self.clock)();
+        let due = self
+            .last_forwarded_ms
+            .is_none_or(|last| now.wrapping_sub(last) >= self.min_interval_ms);
+        if lifecycle || due {
+            self.last_forwarded_ms = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<D: TouchInputDevice, C: FnMut() -> u32, const N: usize> TouchInputDevice
+    for Throttled<D, C, N>
+{
+    type Error = D::Error;
+
+    const MAX_TOUCHES: usize = if D::MAX_TOUCHES < N {
+        D::MAX_TOUCHES
+    } else {
+        N
+    };
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        loop {
+            let touches = self.device.touches()?;
+            let lifecycle = Self::fill(&mut self.buffer, touches);
+            if self.forward(lifecycle) {
+                return Ok(self.buffer.values());
+            }
+        }
+    }
+}
+
+impl<D: AsyncTouchInputDevice, C: FnMut() -> u32, const N: usize> AsyncTouchInputDevice
+    for Throttled<D, C, N>
+{
+    type Error = D::Error;
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        loop {
+            let touches = self.device.touches().await?;
+            let lifecycle = Self::fill(&mut self.buffer, touches);
+            if self.forward(lifecycle) {
+                return Ok(self.buffer.values());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Frames, block_on, finger};
+
+    /// A clock that advances 4 ms per read, as for a 240 Hz controller
+    fn clock() -> impl FnMut() -> u32 {
+        let mut now = 0;
+        move || {
+            now += 4;
+            now
+        }
+    }
+
+    #[test]
+    fn decimates_moves_but_keeps_lifecycle_events() {
+        let frames: &[&[Touch]] = &[
+            &[finger(0, 0, 0, Phase::Started)],
+            &[finger(0, 1, 0, Phase::Moved)],
+            &[finger(0, 2, 0, Phase::Moved)],
+            &[finger(0, 3, 0, Phase::Moved)],
+            &[finger(0, 4, 0, Phase::Moved)],
+            &[finger(0, 5, 0, Phase::Ended)],
+        ];
+        let mut device = Throttled::<_, _, 2>::new(Frames::new(frames), 10, clock());
+
+        let mut read = || {
+            TouchInputDevice::touches(&mut device)
+                .map(|touches| touches.into_iter().map(|touch| touch.location.x).next())
+        };
+        assert_eq!(read(), Ok(Some(0)));
+        assert_eq!(read(), Ok(Some(3)));
+        assert_eq!(read(), Ok(Some(5)), "the end is delivered early");
+        assert_eq!(read(), Err(()));
+    }
+
+    #[test]
+    fn dropped_touches_do_not_force_reads() {
+        let frames: &[&[Touch]] = &[
+            &[finger(0, 0, 0, Phase::Started)],
+            &[finger(0, 1, 0, Phase::Moved), finger(1, 0, 0, Phase::Ended)],
+            &[finger(0, 2, 0, Phase::Moved)],
+            &[
+                finger(0, 3, 0, Phase::Moved),
+                finger(1, 0, 0, Phase::Started),
+            ],
+        ];
+        let mut device = Throttled::<_, _, 1>::new(Frames::new(frames), 10, clock());
+
+        let mut read = || {
+            TouchInputDevice::touches(&mut device)
+                .map(|touches| touches.into_iter().map(|touch| touch.location.x).next())
+        };
+        assert_eq!(read(), Ok(Some(0)));
+        assert_eq!(read(), Ok(Some(3)), "the dropped end does not force a read");
+        assert_eq!(read(), Err(()));
+    }
+
+    #[test]
+    fn async_reads_are_throttled() {
+        let frames: &[&[Touch]] = &[
+            &[finger(0, 0, 0, Phase::Moved)],
+            &[finger(0, 1, 0, Phase::Moved)],
+            &[finger(0, 2, 0, Phase::Moved)],
+            &[finger(0, 3, 0, Phase::Cancelled)],
+        ];
+        let mut device = Throttled::<_, _, 2>::new(Frames::new(frames), 8, clock());
+
+        let touches = block_on(AsyncTouchInputDevice::touches(&mut device)).unwrap();
+        assert!(touches.into_iter().eq([&finger(0, 0, 0, Phase::Moved)]));
+        let touches = block_on(AsyncTouchInputDevice::touches(&mut device)).unwrap();
+        assert!(touches.into_iter().eq([&finger(0, 2, 0, Phase::Moved)]));
+        let touches = block_on(AsyncTouchInputDevice::touches(&mut device)).unwrap();
+        assert!(touches.into_iter().eq([&finger(0, 3, 0, Phase::Cancelled)]));
+    }
+}
@@ -0,0 +1,292 @@
+//! Helpers for assigning touches across frames
+
+use crate::{Phase, Tool, Touch, TouchPoint, id_map::IdMap};
+
+/// Returns the candidate touch closest to `point`
+///
+/// Distances are compared with [`TouchPoint::distance_squared`]. If several candidates
+/// are equally close, the first one is returned. Returns `None` if there are no
+/// candidates.
+pub fn nearest<'a>(
+    point: TouchPoint,
+    candidates: impl Iterator<Item = &'a Touch>,
+) -> Option<&'a Touch> {
+    candidates.min_by_key(|touch| point.distance_squared(&touch.location))
+}
+
+/// Assigns stable IDs to points from controllers that do not track touches themselves
+///
+/// Each frame, points are matched to the touches of the previous frame, closest pairs
+/// first, as long as they are within the distance threshold. Matched points continue
+/// their touch with [`Phase::Moved`], unmatched points start a new touch with a fresh
+/// ID, and touches left without a point are reported as [`Phase::Ended`]. All touches
+/// are reported as [`Tool::Finger`].
+///
+/// Up to `N` touches are tracked at once, and points beyond that in a frame are dropped.
+#[derive(Debug, Clone)]
+pub struct NearestNeighborTracker<const N: usize> {
+    threshold: u32,
+    touches: IdMap<Touch, N>,
+    ended: IdMap<Touch, N>,
+    next_id: u8,
+}
+
+impl<const N: usize> NearestNeighborTracker<N> {
+    /// Create a tracker that matches points moving up to `threshold` pixels per frame
+    #[must_use]
+    pub const fn new(threshold: u32) -> Self {
+        Self {
+            threshold,
+            touches: IdMap::new(),
+            ended: IdMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Feed the points of a new frame, returning the resulting touches
+    ///
+    /// Ended touches are returned first, followed by the touches for each point in the
+    /// order given. IDs ended in a frame are not reused for new touches in the same
+    /// frame.
+    pub fn update(
+        &mut self,
+        points: impl IntoIterator<Item = TouchPoint>,
+    ) -> impl Iterator<Item = &Touch> {
+        let mut points = points.into_iter();
+        let points: [Option<TouchPoint>; N] = core::array::from_fn(|_| points.next());
+        let mut previous: [Option<(u8, TouchPoint)>; N] = [None; N];
+        for (slot, touch) in previous.iter_mut().zip(self.touches.values()) {
+            *slot = Some((touch.id, touch.location));
+        }
+
+        // Greedily match the closest remaining pair until none are within the threshold
+        let mut ids: [Option<u8>; N] = [None; N];
+        let max_distance = i64::from(self.threshold).pow(2);
+        loop {
+            let mut best: Option<(i64, usize, usize)> = None;
+            for (i, from) in previous.iter().enumerate() {
+                let Some((_, from)) = from else { continue };
+                for (j, to) in points.iter().enumerate() {
+                    let Some(to) = to.filter(|_| ids[j].is_none()) else {
+                        continue;
+                    };
+                    let distance = from.distance_squared(&to);
+                    if distance <= max_distance && best.is_none_or(|(d, _, _)| distance < d) {
+                        best = Some((distance, i, j));
+                    }
+                }
+            }
+            let Some((_, i, j)) = best else { break };
+            ids[j] = previous[i].take().map(|(id, _)| id);
+        }
+
+        self.ended.clear();
+        for (id, location) in previous.into_iter().flatten() {
+            _ = self.ended.insert(id, touch(id, location, Phase::Ended));
+        }
+
+        self.touches.clear();
+        for (j, point) in points.into_iter().enumerate() {
+            let Some(point) = point else { continue };
+            let (id, phase) = if let Some(id) = ids[j] {
+                (id, Phase::Moved)
+            } else {
+                let Some(id) = self.allocate_id(&ids) else {
+                    continue;
+                };
+                ids[j] = Some(id);
+                (id, Phase::Started)
+            };
+            _ = self.touches.insert(id, touch(id, point, phase));
+        }
+
+        self.ended.values().chain(self.touches.values())
+    }
+
+    /// Returns an ID not matched this frame and not used by a just-ended touch
+    fn allocate_id(&mut self, matched: &[Option<u8>]) -> Option<u8> {
+        for _ in 0..=u8::MAX {
+            let id = self.next_id;
+            self.next_id = self.next_id.wrapping_add(1);
+            if !matched.contains(&Some(id)) && self.ended.get(id).is_none() {
+                return Some(id);
+            }
+        }
+        None
+    }
+}
+
+/// Allocates touch IDs from the pool `0..N`, for drivers that assign their own IDs
+///
+/// Each allocation returns the lowest free ID. Once a touch has been reported as
+/// [`Phase::Ended`] or [`Phase::Cancelled`], its ID should be freed so it can be reused,
+/// as described for [`Touch::id`]. The pool is a bit set, so `N` can be at most 256.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdAllocator<const N: usize> {
+    allocated: [u64; 4],
+}
+
+impl<const N: usize> IdAllocator<N> {
+    /// Create an allocator with every ID free
+    #[must_use]
+    pub const fn new() -> Self {
+        const { assert!(N <= 256, "touch IDs are limited to u8") };
+        Self { allocated: [0; 4] }
+    }
+
+    /// Allocate the lowest free ID, or `None` if all `N` IDs are in use
+    pub fn allocate(&mut self) -> Option<u8> {
+        let lowest_free = self
+            .allocated
+            .iter()
+            .enumerate()
+            .find_map(|(index, word)| {
+                (*word != u64::MAX).then(|| index * 64 + word.trailing_ones() as usize)
+            })?;
+        let id = u8::try_from(lowest_free)
+            .ok()
+            .filter(|&id| usize::from(id) < N)?;
+        self.allocated[usize::from(id / 64)] |= 1 << (id % 64);
+        Some(id)
+    }
+
+    /// Return an ID to the pool
+    ///
+    /// Freeing an ID that is not allocated has no effect.
+    pub fn free(&mut self, id: u8) {
+        self.allocated[usize::from(id / 64)] &= !(1 << (id % 64));
+    }
+
+    /// Returns `true` if the ID is currently allocated
+    #[must_use]
+    pub fn is_allocated(&self, id: u8) -> bool {
+        self.allocated[usize::from(id / 64)] & (1 << (id % 64)) != 0
+    }
+}
+
+impl<const N: usize> Default for IdAllocator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn touch(id: u8, location: TouchPoint, phase: Phase) -> Touch {
+    Touch::new(id, location, phase, Tool::Finger)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{Phase, test_util::finger};
+
+    #[test]
+    fn nearest_picks_closest_candidate() {
+        let candidates = [
+            finger(0, 100, 100, Phase::Moved),
+            finger(1, 12, 9, Phase::Moved),
+            finger(2, -10, -10, Phase::Moved),
+            finger(3, 9, 12, Phase::Moved),
+        ];
+        let found = nearest(TouchPoint::new(10, 10), candidates.iter());
+        assert_eq!(found.map(|touch| touch.id), Some(1));
+
+        let found = nearest(TouchPoint::new(-50, -50), candidates.iter());
+        assert_eq!(found.map(|touch| touch.id), Some(2));
+
+        assert_eq!(nearest(TouchPoint::new(0, 0), [].iter()), None);
+    }
+
+    fn frame<const N: usize>(
+        tracker: &mut NearestNeighborTracker<N>,
+        points: &[(i32, i32)],
+    ) -> std::vec::Vec<(u8, Phase, (i32, i32))> {
+        tracker
+            .update(points.iter().map(|&point| TouchPoint::from(point)))
+            .map(|touch| (touch.id, touch.phase, touch.location.into()))
+            .collect()
+    }
+
+    #[test]
+    fn tracks_single_moving_point() {
+        let mut tracker = NearestNeighborTracker::<2>::new(20);
+        assert_eq!(
+            frame(&mut tracker, &[(10, 10)]),
+            [(0, Phase::Started, (10, 10))]
+        );
+        assert_eq!(
+            frame(&mut tracker, &[(25, 10)]),
+            [(0, Phase::Moved, (25, 10))]
+        );
+        assert_eq!(
+            frame(&mut tracker, &[(25, 30)]),
+            [(0, Phase::Moved, (25, 30))]
+        );
+        assert_eq!(frame(&mut tracker, &[]), [(0, Phase::Ended, (25, 30))]);
+        assert_eq!(frame(&mut tracker, &[]), []);
+    }
+
+    #[test]
+    fn keeps_ids_of_crossing_points() {
+        let mut tracker = NearestNeighborTracker::<2>::new(15);
+        frame(&mut tracker, &[(0, 0), (100, 40)]);
+        // The points pass each other on parallel tracks, reported in the opposite order
+        for x in (10..=100).step_by(10) {
+            assert_eq!(
+                frame(&mut tracker, &[(100 - x, 40), (x, 0)]),
+                [(1, Phase::Moved, (100 - x, 40)), (0, Phase::Moved, (x, 0))]
+            );
+        }
+    }
+
+    #[test]
+    fn points_appear_and_disappear() {
+        let mut tracker = NearestNeighborTracker::<3>::new(10);
+        frame(&mut tracker, &[(0, 0)]);
+        assert_eq!(
+            frame(&mut tracker, &[(200, 200), (5, 0)]),
+            [(1, Phase::Started, (200, 200)), (0, Phase::Moved, (5, 0))]
+        );
+        // A jump beyond the threshold ends the touch and starts a new one
+        assert_eq!(
+            frame(&mut tracker, &[(200, 205), (50, 0)]),
+            [
+                (0, Phase::Ended, (5, 0)),
+                (1, Phase::Moved, (200, 205)),
+                (2, Phase::Started, (50, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn allocator_exhausts_and_recycles() {
+        let mut ids = IdAllocator::<3>::new();
+        assert_eq!(
+            [ids.allocate(), ids.allocate(), ids.allocate()],
+            [Some(0), Some(1), Some(2)]
+        );
+        assert_eq!(ids.allocate(), None);
+
+        ids.free(1);
+        assert!(!ids.is_allocated(1));
+        assert_eq!(ids.allocate(), Some(1));
+        assert!(ids.is_allocated(1));
+        assert_eq!(ids.allocate(), None);
+
+        // Freeing an unallocated ID is harmless
+        ids.free(200);
+        assert_eq!(ids.allocate(), None);
+    }
+
+    #[test]
+    fn allocator_spans_all_u8_ids() {
+        let mut ids = IdAllocator::<256>::default();
+        for expected in 0..=u8::MAX {
+            assert_eq!(ids.allocate(), Some(expected));
+        }
+        assert_eq!(ids.allocate(), None);
+        ids.free(130);
+        assert_eq!(ids.allocate(), Some(130));
+    }
+}
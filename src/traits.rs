@@ -1,17 +1,111 @@
 use crate::Touch;
+#[cfg(feature = "heapless")]
+use crate::frame::TouchFrame;
+
+/// The number of simultaneous touches assumed for devices that do not declare their own
+/// [`TouchInputDevice::MAX_TOUCHES`]
+pub const DEFAULT_MAX_TOUCHES: usize = 10;
 
 /// Blocking interface for touch devices.
 pub trait TouchInputDevice {
     /// Error type from the underlying interface
     type Error;
 
+    /// The most touches the device reports at once
+    ///
+    /// Callers can use this to size buffers for a concrete device type, e.g.
+    /// `TouchFrame<{ MyDriver::MAX_TOUCHES }>`. Drivers should override the default of
+    /// [`DEFAULT_MAX_TOUCHES`] with the limit of their controller.
+    const MAX_TOUCHES: usize = DEFAULT_MAX_TOUCHES;
+
     /// Read current touch points, blocking until data is available
     ///
     /// Returns an iterator of *all* touch points currently detected.
     /// Drivers must track touch IDs across calls to maintain correct phase information.
+    ///
+    /// Touches are not timestamped. Callers that need timing information should stamp
+    /// touches immediately after this returns, e.g. with [`TimedTouch`](crate::TimedTouch).
     fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error>;
+
+    /// Read current touch points into an owned frame
+    ///
+    /// This lets a frame be kept past the next read. Touches beyond the capacity `N` of
+    /// the frame are silently dropped, including [`Phase::Started`](crate::Phase::Started)
+    /// and [`Phase::Ended`](crate::Phase::Ended) events, so code tracking phases
+    /// downstream can lose a touch. Use [`TouchInputDevice::collect_into`] to detect
+    /// overflow.
+    #[cfg(feature = "heapless")]
+    fn snapshot<const N: usize>(&mut self) -> Result<TouchFrame<N>, Self::Error> {
+        Ok(TouchFrame::truncated(self.touches()?))
+    }
+
+    /// Read current touch points into a caller-provided frame, replacing its contents
+    ///
+    /// Unlike [`TouchInputDevice::snapshot`], which silently drops touches that do not
+    /// fit, this returns [`CollectError::Full`] if the read has more touches than `out`
+    /// can hold. `out` then holds the touches that fit.
+    #[cfg(feature = "heapless")]
+    fn collect_into<const N: usize>(
+        &mut self,
+        out: &mut TouchFrame<N>,
+    ) -> Result<(), CollectError<Self::Error>> {
+        out.clear();
+        for touch in self.touches().map_err(CollectError::Device)? {
+            out.push(touch.clone()).map_err(|_| CollectError::Full)?;
+        }
+        Ok(())
+    }
+
+    /// Read current touch points into an owned frame of [`DEFAULT_MAX_TOUCHES`] capacity
+    ///
+    /// The capacity is always [`DEFAULT_MAX_TOUCHES`], whatever the
+    /// [`TouchInputDevice::MAX_TOUCHES`] of the device, since stable Rust cannot size an
+    /// array by an associated const in generic code. As with
+    /// [`TouchInputDevice::snapshot`], touches beyond the capacity are dropped. With a
+    /// concrete device type, a frame sized to the device can be read with
+    /// `snapshot::<{ MyDriver::MAX_TOUCHES }>()` instead.
+    #[cfg(feature = "heapless")]
+    fn snapshot_default(&mut self) -> Result<TouchFrame<DEFAULT_MAX_TOUCHES>, Self::Error> {
+        self.snapshot()
+    }
+
+    /// Returns an endless iterator of owned frames, one per read
+    ///
+    /// Each call to `next` blocks in [`TouchInputDevice::touches`] until data is
+    /// available, then copies the touches as in [`TouchInputDevice::snapshot`]. Errors are
+    /// yielded as items, and iteration continues after them.
+    #[cfg(feature = "heapless")]
+    fn frames<const N: usize>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<TouchFrame<N>, Self::Error>> {
+        core::iter::from_fn(move || Some(self.snapshot()))
+    }
+}
+
+/// Error returned by [`TouchInputDevice::collect_into`]
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CollectError<E> {
+    /// The device failed
+    Device(E),
+    /// The read had more touches than the frame can hold
+    Full,
+}
+
+#[cfg(feature = "heapless")]
+impl<E: core::fmt::Display> core::fmt::Display for CollectError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CollectError::Device(error) => write!(f, "device: {error}"),
+            CollectError::Full => write!(f, "too many touches for the frame"),
+        }
+    }
 }
 
+#[cfg(feature = "heapless")]
+impl<E: core::error::Error> core::error::Error for CollectError<E> {}
+
 /// Async interface for event-driven operation of touch devices
 pub trait AsyncTouchInputDevice {
     /// Error type from the underlying interface
@@ -21,7 +115,159 @@ pub trait AsyncTouchInputDevice {
     ///
     /// Returns an iterator of *all* touch points currently detected.
     /// Drivers must track touch IDs across calls to maintain correct phase information.
+    ///
+    /// Touches are not timestamped. Callers that need timing information should stamp
+    /// touches as soon as the future resolves, e.g. with
+    /// [`TimedTouch`](crate::TimedTouch).
     fn touches(
         &mut self,
     ) -> impl Future<Output = Result<impl IntoIterator<Item = &Touch>, Self::Error>>;
 }
+
+#[cfg(all(test, feature = "alloc", feature = "heapless"))]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{
+        Phase,
+        mock::{MockTouchDevice, NoMoreFrames},
+        test_util::finger,
+    };
+
+    #[test]
+    fn snapshots_outlive_later_reads() {
+        let mut device = MockTouchDevice::new();
+        device.push_frame([
+            finger(0, 10, 0, Phase::Started),
+            finger(1, 50, 0, Phase::Started),
+        ]);
+        device.push_frame([
+            finger(0, 20, 0, Phase::Moved),
+            finger(1, 50, 0, Phase::Ended),
+            finger(2, 90, 0, Phase::Started),
+        ]);
+
+        let first = device.snapshot::<4>().unwrap();
+        let second = device.snapshot::<2>().unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2, "touches beyond the capacity are dropped");
+        let moved: std::vec::Vec<u8> = second
+            .iter()
+            .filter(|touch| {
+                first
+                    .get_by_id(touch.id)
+                    .is_some_and(|before| before.location != touch.location)
+            })
+            .map(|touch| touch.id)
+            .collect();
+        assert_eq!(moved, [0]);
+        assert_eq!(
+            second.get_by_id(1).map(|touch| touch.phase),
+            Some(Phase::Ended)
+        );
+    }
+
+    #[test]
+    fn frames_iterates_reads() {
+        let mut device = MockTouchDevice::new();
+        for (x, phase) in [(10, Phase::Started), (20, Phase::Moved), (30, Phase::Ended)] {
+            device.push_frame([finger(0, x, 0, phase)]);
+        }
+
+        let mut frames = device.frames::<1>();
+        for (x, phase) in [(10, Phase::Started), (20, Phase::Moved), (30, Phase::Ended)] {
+            let frame = frames.next().unwrap().unwrap();
+            assert_eq!(frame.as_slice(), [finger(0, x, 0, phase)]);
+        }
+        assert_eq!(frames.next(), Some(Err(NoMoreFrames)));
+    }
+
+    #[test]
+    fn collect_into_reports_overflow() {
+        let mut device = MockTouchDevice::new();
+        device.push_frame([
+            finger(0, 0, 0, Phase::Started),
+            finger(1, 0, 0, Phase::Started),
+            finger(2, 0, 0, Phase::Started),
+        ]);
+        device.push_frame([
+            finger(3, 0, 0, Phase::Started),
+            finger(4, 0, 0, Phase::Started),
+            finger(5, 0, 0, Phase::Started),
+        ]);
+
+        let mut small = TouchFrame::<2>::new();
+        assert_eq!(device.collect_into(&mut small), Err(CollectError::Full));
+        assert_eq!(
+            small.as_slice(),
+            [
+                finger(0, 0, 0, Phase::Started),
+                finger(1, 0, 0, Phase::Started)
+            ]
+        );
+
+        let mut large = TouchFrame::<4>::new();
+        large.push(finger(9, 0, 0, Phase::Started)).unwrap();
+        assert_eq!(device.collect_into(&mut large), Ok(()));
+        assert_eq!(
+            large.as_slice(),
+            [
+                finger(3, 0, 0, Phase::Started),
+                finger(4, 0, 0, Phase::Started),
+                finger(5, 0, 0, Phase::Started)
+            ]
+        );
+
+        assert_eq!(
+            device.collect_into(&mut large),
+            Err(CollectError::Device(NoMoreFrames))
+        );
+    }
+
+    struct Pair(MockTouchDevice);
+
+    impl TouchInputDevice for Pair {
+        type Error = NoMoreFrames;
+
+        const MAX_TOUCHES: usize = 2;
+
+        fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+            TouchInputDevice::touches(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn frames_sized_by_max_touches() {
+        assert_eq!(MockTouchDevice::MAX_TOUCHES, DEFAULT_MAX_TOUCHES);
+        assert_eq!(crate::null::NullTouchDevice::MAX_TOUCHES, 0);
+
+        let mut device = Pair(MockTouchDevice::new());
+        device.0.push_frame([
+            finger(0, 0, 0, Phase::Started),
+            finger(1, 0, 0, Phase::Started),
+        ]);
+        device.0.push_frame([
+            finger(2, 0, 0, Phase::Started),
+            finger(3, 0, 0, Phase::Started),
+        ]);
+
+        let frame: TouchFrame<{ Pair::MAX_TOUCHES }> = device.snapshot().unwrap();
+        assert_eq!(
+            frame.as_slice(),
+            [
+                finger(0, 0, 0, Phase::Started),
+                finger(1, 0, 0, Phase::Started)
+            ]
+        );
+        let frame: TouchFrame<DEFAULT_MAX_TOUCHES> = device.snapshot_default().unwrap();
+        assert_eq!(
+            frame.as_slice(),
+            [
+                finger(2, 0, 0, Phase::Started),
+                finger(3, 0, 0, Phase::Started)
+            ]
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Coordinate transforms for mapping raw controller coordinates to screen pixels
+
+use crate::{
+    Touch, TouchPoint,
+    id_map::IdMap,
+    traits::{AsyncTouchInputDevice, TouchInputDevice},
+};
+
+/// A mapping from one coordinate space to another
+pub trait CoordinateTransform {
+    /// Transform a single point
+    fn transform(&self, point: TouchPoint) -> TouchPoint;
+}
+
+impl<T: CoordinateTransform + ?Sized> CoordinateTransform for &T {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        (**self).transform(point)
+    }
+}
+
+/// Applies the first transform, then the second
+impl<A: CoordinateTransform, B: CoordinateTransform> CoordinateTransform for (A, B) {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        self.1.transform(self.0.transform(point))
+    }
+}
+
+/// A clockwise rotation in multiples of 90 degrees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RotationAngle {
+    /// No rotation
+    Deg0,
+    /// 90 degree clockwise rotation
+    Deg90,
+    /// 180 degree rotation
+    Deg180,
+    /// 270 degree clockwise rotation
+    Deg270,
+}
+
+/// Rotates points on a panel clockwise (as viewed with y pointing down) by a multiple
+/// of 90 degrees
+///
+/// The width and height are those of the untransformed panel. After a 90 or 270 degree
+/// rotation, the output space is `height` pixels wide and `width` pixels tall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rotation {
+    angle: RotationAngle,
+    width: u32,
+    height: u32,
+}
+
+impl Rotation {
+    /// Create a new rotation for a panel with the given untransformed dimensions
+    #[must_use]
+    pub fn new(angle: RotationAngle, width: u32, height: u32) -> Self {
+        Self {
+            angle,
+            width,
+            height,
+        }
+    }
+}
+
+impl CoordinateTransform for Rotation {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        match self.angle {
+            RotationAngle::Deg0 => point,
+            RotationAngle::Deg90 => TouchPoint::new(point.flip_y(self.height).y, point.x),
+            RotationAngle::Deg180 => point.flip_x(self.width).flip_y(self.height),
+            RotationAngle::Deg270 => TouchPoint::new(point.y, point.flip_x(self.width).x),
+        }
+    }
+}
+
+/// The axis reflected by a [`Flip`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FlipAxis {
+    /// Mirror left-to-right, reflecting the x coordinate
+    Horizontal,
+    /// Mirror top-to-bottom, reflecting the y coordinate
+    Vertical,
+}
+
+/// Mirrors points on a panel horizontally or vertically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Flip {
+    axis: FlipAxis,
+    width: u32,
+    height: u32,
+}
+
+impl Flip {
+    /// Create a new flip for a panel with the given dimensions
+    #[must_use]
+    pub fn new(axis: FlipAxis, width: u32, height: u32) -> Self {
+        Self {
+            axis,
+            width,
+            height,
+        }
+    }
+}
+
+impl CoordinateTransform for Flip {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        match self.axis {
+            FlipAxis::Horizontal => point.flip_x(self.width),
+            FlipAxis::Vertical => point.flip_y(self.height),
+        }
+    }
+}
+
+/// Shifts points by a fixed offset, saturating at the bounds of `i32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Translation {
+    offset: TouchPoint,
+}
+
+impl Translation {
+    /// Create a new translation by the given offset
+    #[must_use]
+    pub fn new(offset: TouchPoint) -> Self {
+        Self { offset }
+    }
+}
+
+impl CoordinateTransform for Translation {
+    fn transform(&self, point: TouchPoint) -> TouchPoint {
+        point.saturating_add(self.offset)
+    }
+}
+
+/// The axis conventions of a controller, for normalizing its coordinates
+///
+/// Applying the config maps controller coordinates onto a panel with the origin at the
+/// top-left and y pointing down. Inversions are applied first, in the controller's own
+/// coordinate space, using its `width` and `height`, and the axes are swapped last. After
+/// a swap, the output space is `height` pixels wide and `width` pixels tall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AxisConfig {
+    /// Width of the controller's coordinate space, along its x axis
+    pub width: u32,
+    /// Height of the controller's coordinate space, along its y axis
+    pub height: u32,
+    /// The controller's x axis grows leftward
+    pub invert_x: bool,
+    /// The controller's y axis grows upward
+    pub invert_y: bool,
+    /// The controller's x axis runs vertically on the panel, and its y axis horizontally
+    pub swap_axes: bool,
+}
+
+impl AxisConfig {
+    /// Create a config for a controller that already uses the standard conventions
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            invert_x: false,
+            invert_y: false,
+            swap_axes: false,
+        }
+    }
+}
+
+impl CoordinateTransform for AxisConfig {
+    fn transform(&self, mut point: TouchPoint) -> TouchPoint {
+        if self.invert_x {
+            point = point.flip_x(self.width);
+        }
+        if self.invert_y {
+            point = point.flip_y(self.height);
+        }
+        let TouchPoint { x, y } = point;
+        if self.swap_axes {
+            TouchPoint::new(y, x)
+        } else {
+            TouchPoint::new(x, y)
+        }
+    }
+}
+
+/// A touch device adapter that applies a [`CoordinateTransform`] to every touch
+///
+/// Up to `N` touches are buffered per read. Additional touches are dropped.
+#[derive(Debug)]
+pub struct Transformed<D, T, const N: usize> {
+    device: D,
+    transform: T,
+    buffer: IdMap<Touch, N>,
+}
+
+impl<D, T: CoordinateTransform, const N: usize> Transformed<D, T, N> {
+    /// Wrap a device, transforming the location of each of its touches
+    #[must_use]
+    pub fn new(device: D, transform: T) -> Self {
+        Self {
+            device,
+            transform,
+            buffer: IdMap::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped device
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the wrapped device
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the adapter, returning the wrapped device
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    fn fill<'a>(
+        buffer: &mut IdMap<Touch, N>,
+        transform: &T,
+        touches: impl IntoIterator<Item = &'a Touch>,
+    ) {
+        buffer.clear();
+        for touch in touches {
+            let mut touch = touch.clone();
+            touch.location = transform.transform(touch.location);
+            // Touches beyond capacity are dropped
+            _ = buffer.insert(touch.id, touch);
+        }
+    }
+}
+
+impl<D: TouchInputDevice, T: CoordinateTransform, const N: usize> TouchInputDevice
+    for Transformed<D, T, N>
+{
+    type Error = D::Error;
+
+    const MAX_TOUCHES: usize = if D::MAX_TOUCHES < N {
+        D::MAX_TOUCHES
+    } else {
+        N
+    };
+
+    fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        let touches = self.device.touches()?;
+        Self::fill(&mut self.buffer, &self.transform, touches);
+        Ok(self.buffer.values())
+    }
+}
+
+impl<D: AsyncTouchInputDevice, T: CoordinateTransform, const N: usize> AsyncTouchInputDevice
+    for Transformed<D, T, N>
+{
+    type Error = D::Error;
+
+    async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+        let touches = self.device.touches().await?;
+        Self::fill(&mut self.buffer, &self.transform, touches);
+        Ok(self.buffer.values())
+    }
+}
+
+/// A touch device adapter that shifts every touch by a fixed offset
+///
+/// ```
+/// # use embedded_touch::{TouchPoint, transform::{Translated, Translation}};
+/// # fn wrap<D>(device: D) -> Translated<D, 5> {
+/// Translated::<_, 5>::new(device, Translation::new(TouchPoint::new(0, 40)))
+/// # }
+/// ```
+pub type Translated<D, const N: usize> = Transformed<D, Translation, N>;
+
+/// A touch device adapter that normalizes a controller's axis conventions, see
+/// [`AxisConfig`]
+pub type Normalized<D, const N: usize> = Transformed<D, AxisConfig, N>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Phase, Tool, test_util::block_on};
+
+    #[test]
+    fn rotate_origin_through_all_angles() {
+        let origin = TouchPoint::new(0, 0);
+        let rotate = |angle| Rotation::new(angle, 240, 320).transform(origin);
+        assert_eq!(rotate(RotationAngle::Deg0), TouchPoint::new(0, 0));
+        assert_eq!(rotate(RotationAngle::Deg90), TouchPoint::new(319, 0));
+        assert_eq!(rotate(RotationAngle::Deg180), TouchPoint::new(239, 319));
+        assert_eq!(rotate(RotationAngle::Deg270), TouchPoint::new(0, 239));
+    }
+
+    #[test]
+    fn rotate_saturates_on_extreme_sizes() {
+        let origin = TouchPoint::new(0, 0);
+        let empty = Rotation::new(RotationAngle::Deg180, 0, 0);
+        assert_eq!(empty.transform(origin), TouchPoint::new(-1, -1));
+
+        let huge = Rotation::new(RotationAngle::Deg90, u32::MAX, u32::MAX);
+        assert_eq!(huge.transform(origin), TouchPoint::new(i32::MAX, 0));
+        let huge = Rotation::new(RotationAngle::Deg270, u32::MAX, u32::MAX);
+        assert_eq!(
+            huge.transform(TouchPoint::new(i32::MIN, 5)),
+            TouchPoint::new(5, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn flip_and_compose() {
+        let point = TouchPoint::new(10, 20);
+        let horizontal = Flip::new(FlipAxis::Horizontal, 240, 320);
+        let vertical = Flip::new(FlipAxis::Vertical, 240, 320);
+        assert_eq!(horizontal.transform(point), TouchPoint::new(229, 20));
+        assert_eq!(vertical.transform(point), TouchPoint::new(10, 299));
+
+        // Flipping after a quarter turn uses the rotated panel dimensions
+        let transpose = (
+            Rotation::new(RotationAngle::Deg90, 240, 320),
+            Flip::new(FlipAxis::Horizontal, 320, 240),
+        );
+        assert_eq!(transpose.transform(point), TouchPoint::new(20, 10));
+    }
+
+    struct Fixed([Touch; 2]);
+
+    impl TouchInputDevice for Fixed {
+        type Error = ();
+
+        fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+            Ok(&self.0)
+        }
+    }
+
+    impl AsyncTouchInputDevice for Fixed {
+        type Error = ();
+
+        async fn touches(&mut self) -> Result<impl IntoIterator<Item = &Touch>, Self::Error> {
+            Ok(&self.0)
+        }
+    }
+
+    #[test]
+    fn translate_multi_touch_frame() {
+        let touches = [
+            Touch::new(2, TouchPoint::new(5, 10), Phase::Started, Tool::Finger),
+            Touch::new(7, TouchPoint::new(-5, 100), Phase::Moved, Tool::Finger),
+        ];
+        let offset = Translation::new(TouchPoint::new(20, -10));
+        let mut device = Translated::<_, 2>::new(Fixed(touches), offset);
+
+        let mut touches = TouchInputDevice::touches(&mut device).unwrap().into_iter();
+        assert_eq!(
+            touches.next().map(|t| (t.id, t.location)),
+            Some((2, TouchPoint::new(25, 0)))
+        );
+        assert_eq!(
+            touches.next().map(|t| (t.id, t.location)),
+            Some((7, TouchPoint::new(15, 90)))
+        );
+        assert_eq!(touches.next(), None);
+    }
+
+    #[test]
+    fn translate_saturates() {
+        let offset = Translation::new(TouchPoint::new(10, -10));
+        assert_eq!(
+            offset.transform(TouchPoint::new(i32::MAX - 5, i32::MIN + 5)),
+            TouchPoint::new(i32::MAX, i32::MIN)
+        );
+    }
+
+    #[test]
+    fn transform_device_touches() {
+        let touches = [
+            Touch::new(0, TouchPoint::new(0, 0), Phase::Started, Tool::Finger),
+            Touch::new(1, TouchPoint::new(239, 319), Phase::Moved, Tool::Finger),
+        ];
+        let rotation = Rotation::new(RotationAngle::Deg180, 240, 320);
+        let mut device = Transformed::<_, _, 4>::new(Fixed(touches), rotation);
+
+        let transformed: [Touch; 2] = [
+            Touch::new(0, TouchPoint::new(239, 319), Phase::Started, Tool::Finger),
+            Touch::new(1, TouchPoint::new(0, 0), Phase::Moved, Tool::Finger),
+        ];
+        let mut touches = TouchInputDevice::touches(&mut device).unwrap().into_iter();
+        assert_eq!(touches.next(), Some(&transformed[0]));
+        assert_eq!(touches.next(), Some(&transformed[1]));
+        assert_eq!(touches.next(), None);
+    }
+
+    #[test]
+    fn async_transform_device_touches() {
+        let touches = [
+            Touch::new(0, TouchPoint::new(10, 20), Phase::Started, Tool::Finger),
+            Touch::new(1, TouchPoint::new(230, 300), Phase::Moved, Tool::Finger),
+        ];
+        let rotation = Rotation::new(RotationAngle::Deg180, 240, 320);
+        let mut device = Transformed::<_, _, 4>::new(Fixed(touches), rotation);
+
+        let transformed = [
+            Touch::new(0, TouchPoint::new(229, 299), Phase::Started, Tool::Finger),
+            Touch::new(1, TouchPoint::new(9, 19), Phase::Moved, Tool::Finger),
+        ];
+        let touches = block_on(AsyncTouchInputDevice::touches(&mut device)).unwrap();
+        assert!(touches.into_iter().eq(&transformed));
+    }
+
+    #[test]
+    fn normalize_axis_conventions() {
+        let point = TouchPoint::new(10, 20);
+        let config = AxisConfig::new(240, 320);
+        assert_eq!(config.transform(point), point);
+
+        let invert_x = AxisConfig {
+            invert_x: true,
+            ..config
+        };
+        assert_eq!(invert_x.transform(point), TouchPoint::new(229, 20));
+        let invert_y = AxisConfig {
+            invert_y: true,
+            ..config
+        };
+        assert_eq!(invert_y.transform(point), TouchPoint::new(10, 299));
+        let swap = AxisConfig {
+            swap_axes: true,
+            ..config
+        };
+        assert_eq!(swap.transform(point), TouchPoint::new(20, 10));
+
+        // Inversion uses the controller's dimensions, before the swap
+        let all = AxisConfig {
+            invert_x: true,
+            invert_y: true,
+            swap_axes: true,
+            ..config
+        };
+        assert_eq!(all.transform(point), TouchPoint::new(299, 229));
+    }
+
+    #[test]
+    fn normalized_device() {
+        let touches = [
+            Touch::new(0, TouchPoint::new(0, 0), Phase::Started, Tool::Finger),
+            Touch::new(1, TouchPoint::new(10, 20), Phase::Moved, Tool::Finger),
+        ];
+        let config = AxisConfig {
+            invert_y: true,
+            ..AxisConfig::new(240, 320)
+        };
+        let mut device = Normalized::<_, 2>::new(Fixed(touches), config);
+        let mut touches = TouchInputDevice::touches(&mut device).unwrap().into_iter();
+        assert_eq!(
+            touches.next().map(|t| (t.id, t.location)),
+            Some((0, TouchPoint::new(0, 319)))
+        );
+        assert_eq!(
+            touches.next().map(|t| (t.id, t.location)),
+            Some((1, TouchPoint::new(10, 299)))
+        );
+        assert_eq!(touches.next(), None);
+    }
+}
@@ -0,0 +1,202 @@
+//! Fixed-point CORDIC trigonometry
+
+use crate::UnitAngle;
+
+/// `atan(2^-i)` in units of 2^-32 turns
+const ATAN_TABLE: [u32; 30] = [
+    536_870_912,
+    316_933_406,
+    167_458_907,
+    85_004_756,
+    42_667_331,
+    21_354_465,
+    10_679_838,
+    5_340_245,
+    2_670_163,
+    1_335_087,
+    667_544,
+    333_772,
+    166_886,
+    83_443,
+    41_722,
+    20_861,
+    10_430,
+    5_215,
+    2_608,
+    1_304,
+    652,
+    326,
+    163,
+    81,
+    41,
+    20,
+    10,
+    5,
+    3,
+    1,
+];
+
+/// Returns the angle of the vector `(x, y)`, measured from the +x axis toward the +y axis
+///
+/// Returns `None` for the zero vector. Components must be smaller than 2^60 in magnitude.
+pub(crate) fn atan2(y: i64, x: i64) -> Option<UnitAngle> {
+    if x == 0 && y == 0 {
+        return None;
+    }
+
+    // Rotate into the right half-plane, where CORDIC converges
+    let (mut x, mut y, mut turns) = if x < 0 {
+        (-x, -y, 1u32 << 31)
+    } else {
+        (x, y, 0)
+    };
+
+    // Scale up small vectors to preserve precision, leaving headroom for the CORDIC gain
+    let shift = x
+        .unsigned_abs()
+        .max(y.unsigned_abs())
+        .leading_zeros()
+        .saturating_sub(3);
+    x <<= shift;
+    y <<= shift;
+
+    for (i, step) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            turns = turns.wrapping_add(*step);
+        } else {
+            x -= dx;
+            y += dy;
+            turns = turns.wrapping_sub(*step);
+        }
+    }
+
+    // Round from 2^-32 turns to the 2^-16 turn resolution of UnitAngle
+    Some(UnitAngle::from_raw(
+        (turns.wrapping_add(1 << 15) >> 16) as u16,
+    ))
+}
+
+/// Reciprocal of the CORDIC gain after all iterations, in units of 2^-30
+const INVERSE_GAIN: i64 = 652_032_874;
+
+/// Returns the sine and cosine of an angle, in units of 2^-30
+///
+/// Multiples of 90° are exact.
+pub(crate) fn sin_cos(angle: UnitAngle) -> (i32, i32) {
+    const ONE: i32 = 1 << 30;
+
+    // Split into the nearest quarter turn and a remainder within ±45°
+    let turns = u32::from(angle.as_raw()) << 16;
+    let quadrant = turns.wrapping_add(1 << 29) >> 30;
+    let mut z = turns.wrapping_sub(quadrant << 30).cast_signed();
+
+    let (sin, cos) = if z == 0 {
+        (0, ONE)
+    } else {
+        let (mut x, mut y) = (INVERSE_GAIN, 0i64);
+        for (i, step) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            let step = step.cast_signed();
+            if z >= 0 {
+                x -= dx;
+                y += dy;
+                z -= step;
+            } else {
+                x += dx;
+                y -= dy;
+                z += step;
+            }
+        }
+        // Both components are within ±1 + ε, so they fit in an i32
+        #[expect(clippy::cast_possible_truncation)]
+        (y as i32, x as i32)
+    };
+
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atan2_axes_and_diagonals() {
+        let cases = [
+            ((0, 1), 0x0000),
+            ((1, 1), 0x2000),
+            ((1, 0), 0x4000),
+            ((1, -1), 0x6000),
+            ((0, -1), 0x8000),
+            ((-1, -1), 0xA000),
+            ((-1, 0), 0xC000),
+            ((-1, 1), 0xE000),
+        ];
+        for ((y, x), raw) in cases {
+            let angle = atan2(y * 1000, x * 1000).unwrap();
+            assert!(
+                angle.as_raw().abs_diff(raw) <= 1,
+                "atan2({y}, {x}) = {:#x}, expected {raw:#x}",
+                angle.as_raw()
+            );
+        }
+        assert_eq!(atan2(0, 0), None);
+    }
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)]
+    fn atan2_sweep() {
+        for degrees in 0..360 {
+            let radians = f64::from(degrees).to_radians();
+            // Approximate sin/cos with a Taylor series, since core has no float math
+            let angle = UnitAngle::from_degrees(degrees);
+            let expected = angle.as_raw();
+            let (sin, cos) = taylor_sin_cos(radians);
+            let actual = atan2((sin * 1e6) as i64, (cos * 1e6) as i64).unwrap();
+            let error = actual.as_raw().wrapping_sub(expected).cast_signed();
+            assert!(error.abs() <= 2, "{degrees}° off by {error}");
+        }
+    }
+
+    #[test]
+    fn sin_cos_sweep() {
+        for degrees in 0..360 {
+            let angle = UnitAngle::from_degrees(degrees);
+            let radians = f64::from(angle.as_raw()) / 65536.0 * 2.0 * core::f64::consts::PI;
+            let (expected_sin, expected_cos) = taylor_sin_cos(radians);
+            let (sin, cos) = sin_cos(angle);
+            let scale = f64::from(1 << 30);
+            assert!(
+                (f64::from(sin) / scale - expected_sin).abs() < 1e-6,
+                "sin {degrees}°"
+            );
+            assert!(
+                (f64::from(cos) / scale - expected_cos).abs() < 1e-6,
+                "cos {degrees}°"
+            );
+        }
+        assert_eq!(sin_cos(UnitAngle::from_raw(0x4000)), (1 << 30, 0));
+        assert_eq!(sin_cos(UnitAngle::from_raw(0xC000)), (-(1 << 30), 0));
+    }
+
+    fn taylor_sin_cos(radians: f64) -> (f64, f64) {
+        let (mut sin, mut cos) = (0.0, 0.0);
+        let mut term = 1.0;
+        for n in 0..30 {
+            if n % 2 == 0 {
+                cos += if n % 4 == 0 { term } else { -term };
+            } else {
+                sin += if n % 4 == 1 { term } else { -term };
+            }
+            term *= radians / f64::from(n + 1);
+        }
+        (sin, cos)
+    }
+}
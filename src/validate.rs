@@ -0,0 +1,191 @@
+//! Validation of the phase sequences reported by touch drivers
+
+use core::fmt;
+
+use crate::{Phase, id_map::IdMap};
+
+/// Checks that each touch ID follows a legal sequence of phases
+///
+/// A touch in contact must begin with [`Phase::Started`], may then report any number of
+/// [`Phase::Moved`] or [`Phase::Stationary`], and finishes with [`Phase::Ended`] or
+/// [`Phase::Cancelled`], after which its ID is free to start again. A touch may hover
+/// before it starts, and may go back to hovering when it lifts off; a hovering touch
+/// may also end without making contact.
+///
+/// Up to `N` touches are tracked at once.
+#[derive(Debug, Clone)]
+pub struct PhaseTracker<const N: usize> {
+    phases: IdMap<Phase, N>,
+}
+
+impl<const N: usize> PhaseTracker<N> {
+    /// Create a tracker with no touches in progress
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            phases: IdMap::new(),
+        }
+    }
+
+    /// Record the next phase reported for a touch ID
+    ///
+    /// Illegal transitions are rejected without changing the tracked state.
+    pub fn update(&mut self, id: u8, phase: Phase) -> Result<(), PhaseError> {
+        let previous = self.phases.get(id).copied();
+        let legal = matches!(
+            (previous, phase),
+            (None | Some(Phase::Hovering(_)), Phase::Started)
+                | (
+                    Some(Phase::Started | Phase::Moved | Phase::Stationary),
+                    Phase::Moved | Phase::Stationary
+                )
+                | (Some(_), Phase::Ended | Phase::Cancelled)
+                | (_, Phase::Hovering(_))
+        );
+        if !legal {
+            return Err(PhaseError::IllegalTransition {
+                id,
+                previous,
+                phase,
+            });
+        }
+
+        if phase.is_terminal() {
+            self.phases.remove(id);
+            Ok(())
+        } else {
+            self.phases
+                .insert(id, phase)
+                .map_err(|_| PhaseError::TooManyTouches { id })
+        }
+    }
+
+    /// Returns the last phase recorded for a touch ID that has not yet ended
+    #[must_use]
+    pub fn phase(&self, id: u8) -> Option<Phase> {
+        self.phases.get(id).copied()
+    }
+
+    /// Forget all touches in progress
+    pub fn reset(&mut self) {
+        self.phases.clear();
+    }
+}
+
+impl<const N: usize> Default for PhaseTracker<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned by [`PhaseTracker::update`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PhaseError {
+    /// The phase cannot follow the previous phase of the touch
+    IllegalTransition {
+        /// ID of the offending touch
+        id: u8,
+        /// Previous phase of the touch, or `None` if it was not in progress
+        previous: Option<Phase>,
+        /// The rejected phase
+        phase: Phase,
+    },
+    /// A new touch started while `N` touches were already in progress
+    TooManyTouches {
+        /// ID of the touch that could not be tracked
+        id: u8,
+    },
+}
+
+impl fmt::Display for PhaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhaseError::IllegalTransition {
+                id,
+                previous: Some(previous),
+                phase,
+            } => write!(f, "touch {id} reported {phase:?} after {previous:?}"),
+            PhaseError::IllegalTransition {
+                id,
+                previous: None,
+                phase,
+            } => write!(f, "touch {id} reported {phase:?} before starting"),
+            PhaseError::TooManyTouches { id } => {
+                write!(f, "touch {id} exceeds the tracker capacity")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PhaseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_lifecycles() {
+        let mut tracker = PhaseTracker::<2>::new();
+        let sequence = [
+            (0, Phase::Hovering(Some(3))),
+            (0, Phase::Started),
+            (1, Phase::Started),
+            (0, Phase::Moved),
+            (0, Phase::Stationary),
+            (1, Phase::Moved),
+            (1, Phase::Cancelled),
+            (0, Phase::Ended),
+            (0, Phase::Started),
+            (0, Phase::Hovering(None)),
+            (0, Phase::Ended),
+        ];
+        for (id, phase) in sequence {
+            assert_eq!(tracker.update(id, phase), Ok(()), "{id} {phase:?}");
+        }
+        assert_eq!(tracker.phase(0), None);
+    }
+
+    #[test]
+    fn rejects_illegal_transitions() {
+        let mut tracker = PhaseTracker::<2>::new();
+        assert_eq!(
+            tracker.update(0, Phase::Moved),
+            Err(PhaseError::IllegalTransition {
+                id: 0,
+                previous: None,
+                phase: Phase::Moved,
+            })
+        );
+
+        tracker.update(0, Phase::Started).unwrap();
+        assert_eq!(
+            tracker.update(0, Phase::Started),
+            Err(PhaseError::IllegalTransition {
+                id: 0,
+                previous: Some(Phase::Started),
+                phase: Phase::Started,
+            })
+        );
+
+        tracker.update(0, Phase::Ended).unwrap();
+        for phase in [Phase::Moved, Phase::Ended, Phase::Cancelled] {
+            assert!(tracker.update(0, phase).is_err(), "{phase:?} after Ended");
+        }
+
+        tracker.update(1, Phase::Hovering(None)).unwrap();
+        assert!(tracker.update(1, Phase::Moved).is_err());
+        assert_eq!(tracker.phase(1), Some(Phase::Hovering(None)));
+    }
+
+    #[test]
+    fn reports_capacity_overflow() {
+        let mut tracker = PhaseTracker::<1>::new();
+        tracker.update(0, Phase::Started).unwrap();
+        assert_eq!(
+            tracker.update(1, Phase::Started),
+            Err(PhaseError::TooManyTouches { id: 1 })
+        );
+        assert_eq!(tracker.update(0, Phase::Moved), Ok(()));
+    }
+}